@@ -0,0 +1,139 @@
+use hearts_game::CompletedHeartsGame;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Per-strategy outcome summary across a batch of completed games: win count, final
+/// score distribution (mean/median/std-dev/percentiles), a histogram of final scores,
+/// and how often that strategy shot the moon (took all 26 points in a hand).
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub mean_score: f64,
+    pub median_score: f64,
+    pub std_dev_score: f64,
+    pub p25_score: f64,
+    pub p75_score: f64,
+    pub shoot_the_moon_count: u32,
+    pub score_histogram: HashMap<u8, u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GameAnalysis {
+    pub per_strategy: HashMap<String, StrategyStats>,
+}
+
+pub fn analyze(games: &[CompletedHeartsGame]) -> GameAnalysis {
+    let mut scores_by_strategy: HashMap<String, Vec<u8>> = HashMap::new();
+    let mut wins_by_strategy: HashMap<String, u32> = HashMap::new();
+    let mut moon_shots_by_strategy: HashMap<String, u32> = HashMap::new();
+
+    for game in games {
+        for (index, player) in game.players.iter().enumerate() {
+            scores_by_strategy
+                .entry(player.strategy.clone())
+                .or_default()
+                .push(player.score);
+            if index == game.winner_index {
+                *wins_by_strategy.entry(player.strategy.clone()).or_default() += 1;
+            }
+            if player.score == 26 {
+                *moon_shots_by_strategy
+                    .entry(player.strategy.clone())
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    let per_strategy = scores_by_strategy
+        .into_iter()
+        .map(|(strategy, mut scores)| {
+            scores.sort();
+            let mean_score = mean(&scores);
+            let mut score_histogram: HashMap<u8, u32> = HashMap::new();
+            for &score in &scores {
+                *score_histogram.entry(score).or_insert(0) += 1;
+            }
+
+            let stats = StrategyStats {
+                games_played: scores.len() as u32,
+                wins: wins_by_strategy.get(&strategy).copied().unwrap_or(0),
+                mean_score,
+                median_score: percentile(&scores, 0.5),
+                std_dev_score: std_dev(&scores, mean_score),
+                p25_score: percentile(&scores, 0.25),
+                p75_score: percentile(&scores, 0.75),
+                shoot_the_moon_count: moon_shots_by_strategy.get(&strategy).copied().unwrap_or(0),
+                score_histogram,
+            };
+            (strategy, stats)
+        })
+        .collect();
+
+    GameAnalysis { per_strategy }
+}
+
+fn mean(scores: &[u8]) -> f64 {
+    if scores.is_empty() {
+        return 0.0;
+    }
+    scores.iter().map(|&s| s as f64).sum::<f64>() / scores.len() as f64
+}
+
+fn std_dev(scores: &[u8], mean_score: f64) -> f64 {
+    if scores.is_empty() {
+        return 0.0;
+    }
+    let variance = scores
+        .iter()
+        .map(|&s| (s as f64 - mean_score).powi(2))
+        .sum::<f64>()
+        / scores.len() as f64;
+    variance.sqrt()
+}
+
+/// Linear-interpolated percentile of an already-sorted slice, generalizing the
+/// two-point interpolation `GameMovesFilter`'s threshold test prototyped (`low +
+/// (high - low) * fraction`) to an arbitrary percentile `p` in `0.0..=1.0`.
+fn percentile(sorted_scores: &[u8], p: f64) -> f64 {
+    if sorted_scores.is_empty() {
+        return 0.0;
+    }
+    if sorted_scores.len() == 1 {
+        return sorted_scores[0] as f64;
+    }
+    let rank = p * (sorted_scores.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let fraction = rank - lower as f64;
+    sorted_scores[lower] as f64
+        + (sorted_scores[upper] as f64 - sorted_scores[lower] as f64) * fraction
+}
+
+pub fn print_summary(analysis: &GameAnalysis) {
+    println!("\nStrategy Analysis:");
+    println!(
+        "{:<15} | {:>5} | {:>5} | {:>6} | {:>6} | {:>6} | {:>11} | {:>5}",
+        "Strategy", "Games", "Wins", "Mean", "Median", "StdDev", "P25 / P75", "Moons"
+    );
+    println!("{}", "-".repeat(80));
+
+    let mut strategies: Vec<&String> = analysis.per_strategy.keys().collect();
+    strategies.sort();
+
+    for strategy in strategies {
+        let stats = &analysis.per_strategy[strategy];
+        println!(
+            "{:<15} | {:>5} | {:>5} | {:>6.1} | {:>6.1} | {:>6.1} | {:>4.1} / {:<4.1} | {:>5}",
+            strategy,
+            stats.games_played,
+            stats.wins,
+            stats.mean_score,
+            stats.median_score,
+            stats.std_dev_score,
+            stats.p25_score,
+            stats.p75_score,
+            stats.shoot_the_moon_count,
+        );
+    }
+}
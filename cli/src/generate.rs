@@ -2,6 +2,7 @@ use chrono::Utc;
 use hearts_game::{
     AIStrategy, AggressiveStrategy, Deck, HeartsGame, MyStrategy, RandomStrategy, Strategy,
 };
+use rayon::prelude::*;
 use serde_json;
 use std::fs::{self, File};
 use std::io::BufWriter;
@@ -10,7 +11,18 @@ use std::time::Instant;
 
 use crate::stats::display_statistics;
 
-pub fn generate_games(num_games: usize, use_same_deck_for_all_players: bool) {
+/// Strategies that do blocking network I/O (the Python `AIStrategy` endpoint, or a
+/// live `Remote` player) shouldn't be fanned out across every core, since that just
+/// saturates the connection rather than speeding anything up.
+const BLOCKING_IO_WORKERS: usize = 4;
+
+fn does_blocking_io(player_configs: &[(&str, Strategy)]) -> bool {
+    player_configs
+        .iter()
+        .any(|(_, strategy)| matches!(strategy, Strategy::AI(_) | Strategy::Remote(_)))
+}
+
+pub fn generate_games(num_games: usize, use_same_deck_for_all_players: bool, save_replay: bool) {
     println!(
         "Generating {} games using {}...",
         num_games,
@@ -38,47 +50,96 @@ pub fn generate_games(num_games: usize, use_same_deck_for_all_players: bool) {
 
     save_results(num_games, &results);
 
+    if save_replay {
+        crate::replay::save_replays(&PathBuf::from("data/replays"), &results);
+        println!("Replays saved to: data/replays");
+    }
+
     let duration = start.elapsed();
     println!("Time to play and save {} games: {:?}", num_games, duration);
     println!("Average time per game: {:?}", duration / num_games as u32);
+    println!(
+        "Throughput: {:.1} games/sec across {} cores",
+        num_games as f64 / duration.as_secs_f64(),
+        rayon::current_num_threads()
+    );
 
     display_statistics(&results);
 }
 
-fn generate_with_random_decks(
+pub(crate) fn generate_with_random_decks(
     num_games: usize,
     player_configs: &[(&str, Strategy)],
 ) -> Vec<hearts_game::CompletedHeartsGame> {
-    let mut results = Vec::with_capacity(num_games);
-    for _ in 0..num_games {
-        let mut game = HeartsGame::new(&player_configs);
+    let play_one = |_| {
+        let mut game = HeartsGame::new(player_configs);
         game.play_game();
-        results.push(game.completed_game());
+        game.completed_game()
+    };
+
+    if does_blocking_io(player_configs) {
+        build_pool().install(|| (0..num_games).into_par_iter().map(play_one).collect())
+    } else {
+        (0..num_games).into_par_iter().map(play_one).collect()
     }
-    results
 }
 
-fn generate_with_same_deck(
+/// Plays `num_games` independent games that all start from (a clone of) the same
+/// deck, used by the mgmt daemon when a harness pins a deterministic deal via
+/// `SetDeck`.
+pub(crate) fn generate_with_deck(
     num_games: usize,
     player_configs: &[(&str, Strategy)],
+    deck: Deck,
 ) -> Vec<hearts_game::CompletedHeartsGame> {
-    let mut results = Vec::with_capacity(num_games);
-    let mut game_index = 0;
-    let mut current_deck = Deck::new(None);
-
-    for _ in 0..num_games {
-        let mut game = HeartsGame::new_with_deck(player_configs, Some(current_deck.clone()));
+    let play_one = |_| {
+        let mut game = HeartsGame::new_with_deck(player_configs, Some(deck.clone()));
         game.play_game();
-        results.push(game.completed_game());
+        game.completed_game()
+    };
 
-        game_index += 1;
-        if game_index % 4 == 0 {
+    if does_blocking_io(player_configs) {
+        build_pool().install(|| (0..num_games).into_par_iter().map(play_one).collect())
+    } else {
+        (0..num_games).into_par_iter().map(play_one).collect()
+    }
+}
+
+fn generate_with_same_deck(
+    num_games: usize,
+    player_configs: &[(&str, Strategy)],
+) -> Vec<hearts_game::CompletedHeartsGame> {
+    // The deck-rotation dependency (rotate every game, reshuffle every 4th) has to
+    // be precomputed sequentially so the parallel map below stays deterministic.
+    let mut decks = Vec::with_capacity(num_games);
+    let mut current_deck = Deck::new(None);
+    for game_index in 0..num_games {
+        decks.push(current_deck.clone());
+        if (game_index + 1) % 4 == 0 {
             current_deck = Deck::new(None);
         } else {
             current_deck = current_deck.rotate(13);
         }
     }
-    results
+
+    let play_one = |deck: Deck| {
+        let mut game = HeartsGame::new_with_deck(player_configs, Some(deck));
+        game.play_game();
+        game.completed_game()
+    };
+
+    if does_blocking_io(player_configs) {
+        build_pool().install(|| decks.into_par_iter().map(play_one).collect())
+    } else {
+        decks.into_par_iter().map(play_one).collect()
+    }
+}
+
+fn build_pool() -> rayon::ThreadPool {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(BLOCKING_IO_WORKERS)
+        .build()
+        .expect("failed to build bounded worker pool")
 }
 
 fn save_results(num_games: usize, results: &Vec<hearts_game::CompletedHeartsGame>) {
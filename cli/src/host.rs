@@ -0,0 +1,50 @@
+use hearts_game::{AvoidPointsStrategy, GameServer, HeartsGame, RemoteStrategy, Strategy};
+use std::net::SocketAddr;
+use std::time::Duration;
+
+/// Start a websocket listener and host a single live game where `seat` is played
+/// remotely by whichever client connects and joins that seat; the other three
+/// seats are filled with a simple built-in strategy.
+pub fn host_game(addr: SocketAddr, seat: usize) {
+    let runtime = tokio::runtime::Runtime::new().expect("failed to start async runtime");
+    let server = GameServer::new(Duration::from_secs(30));
+
+    runtime.block_on(async {
+        let listener = server.clone();
+        let accept_addr = addr;
+        tokio::spawn(async move {
+            if let Err(err) = listener.listen(accept_addr).await {
+                eprintln!("host listener stopped: {}", err);
+            }
+        });
+
+        println!("Hosting game on {}, seat {} is remote", addr, seat);
+
+        // `game.play_game()` is synchronous and, via `RemoteStrategy`, calls back into
+        // `GameServer::request_move`, which blocks on this same runtime to await the
+        // remote client's reply. Running that on the runtime's own async thread would
+        // make that a reentrant `block_on` (tokio panics: "Cannot start a runtime from
+        // within a runtime"), so the whole game loop runs on a blocking-pool thread
+        // instead, where blocking on the runtime handle is fine.
+        let game_server = server.clone();
+        let completed = tokio::task::spawn_blocking(move || {
+            let player_configs = [
+                ("Remote", Strategy::Remote(RemoteStrategy::new(seat, game_server))),
+                ("Bob", Strategy::AvoidPoints(AvoidPointsStrategy)),
+                ("Charlie", Strategy::AvoidPoints(AvoidPointsStrategy)),
+                ("David", Strategy::AvoidPoints(AvoidPointsStrategy)),
+            ];
+
+            let mut game = HeartsGame::new(&player_configs);
+            game.play_game();
+            game.completed_game()
+        })
+        .await
+        .expect("game thread panicked");
+
+        println!(
+            "Game finished, winner seat: {}",
+            completed.winner_index
+        );
+    });
+}
@@ -1,7 +1,12 @@
+mod analysis;
 mod game_moves_filter;
 mod generate;
+mod host;
+mod mgmt;
 mod models;
+mod replay;
 mod stats;
+mod stats_server;
 mod training;
 
 use clap::{Parser, Subcommand};
@@ -20,6 +25,14 @@ enum Commands {
         /// Number of games to simulate
         #[arg(short, long, default_value_t = 1)]
         num_games: usize,
+
+        /// Use the same (rotated) deck for every game instead of a fresh random one
+        #[arg(short = 'd', long)]
+        use_same_deck: bool,
+
+        /// Also save a trick-by-trick replay file for each game
+        #[arg(short = 'r', long)]
+        save_replay: bool,
     },
 
     /// Generate AI training data from simulated games
@@ -35,6 +48,48 @@ enum Commands {
         /// Also save training data to a separate file
         #[arg(short = 'j', long)]
         save_as_json: bool,
+
+        /// Also save a trick-by-trick replay file for each game
+        #[arg(short = 'r', long)]
+        save_replay: bool,
+
+        /// Base RNG seed; each game's deck is derived from this seed plus its game
+        /// index, so the exact same run can be regenerated later. Omit for
+        /// thread-local randomness.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Player table as "Name:tag,Name:tag,Name:tag,Name:tag" (tags use the same
+        /// vocabulary as the mgmt daemon's SetPlayer: random, avoid, aggressive,
+        /// ai:<url>, mcts:<iterations>, dmc:<samples>[:<rollouts_per_sample>]).
+        /// Defaults to the Alice/Bob/Charlie/David mix below when omitted.
+        #[arg(long)]
+        players: Option<String>,
+    },
+
+    /// Host a live game over a websocket, letting one seat be played remotely
+    HostGame {
+        /// Address to listen on, e.g. 127.0.0.1:9001
+        #[arg(short, long, default_value = "127.0.0.1:9001")]
+        addr: String,
+
+        /// Seat index (0-3) that will be controlled by the remote client
+        #[arg(short, long, default_value_t = 0)]
+        seat: usize,
+    },
+
+    /// Run an HTTP ingest server that folds streamed game results into one aggregate
+    StatsServer {
+        /// Address to listen on, e.g. 127.0.0.1:9002
+        #[arg(short, long, default_value = "127.0.0.1:9002")]
+        addr: String,
+    },
+
+    /// Run a reconfigurable simulation daemon controlled over a Unix socket
+    MgmtDaemon {
+        /// Path to the Unix socket to listen on
+        #[arg(short, long, default_value = "/tmp/hearts-mgmt.sock")]
+        socket: String,
     },
 }
 
@@ -42,15 +97,41 @@ fn main() {
     let cli = Cli::parse();
 
     match &cli.command {
-        Commands::GenerateGames { num_games } => {
-            generate::generate_games(*num_games);
+        Commands::GenerateGames {
+            num_games,
+            use_same_deck,
+            save_replay,
+        } => {
+            generate::generate_games(*num_games, *use_same_deck, *save_replay);
         }
         Commands::GenerateAiTrainingData {
             num_games,
             save_games,
             save_as_json,
+            save_replay,
+            seed,
+            players,
         } => {
-            training::generate_training_data(*num_games, *save_games, *save_as_json);
+            training::generate_training_data(
+                *num_games,
+                *save_games,
+                *save_as_json,
+                *save_replay,
+                *seed,
+                players.clone(),
+            );
+        }
+        Commands::HostGame { addr, seat } => {
+            let addr = addr.parse().expect("invalid listen address");
+            host::host_game(addr, *seat);
+        }
+        Commands::StatsServer { addr } => {
+            let addr = addr.parse().expect("invalid listen address");
+            let runtime = tokio::runtime::Runtime::new().expect("failed to start async runtime");
+            runtime.block_on(stats_server::run(addr));
+        }
+        Commands::MgmtDaemon { socket } => {
+            mgmt::run_daemon(std::path::Path::new(socket));
         }
     }
 }
@@ -0,0 +1,219 @@
+use hearts_game::{AIStrategy, AggressiveStrategy, Deck, MyStrategy, RandomStrategy, Strategy};
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::sync::{Arc, Mutex};
+
+use crate::generate::{generate_with_deck, generate_with_random_decks};
+
+/// Commands a controlling harness can send to reconfigure the simulator between
+/// batches without recompiling, framed as length-delimited JSON over a Unix socket
+/// (the same shape as the otter crate's `MgmtChannel`).
+#[derive(Debug, Deserialize)]
+#[serde(tag = "command")]
+pub enum MgmtCommand {
+    SetPlayer {
+        seat: usize,
+        name: String,
+        strategy_spec: String,
+    },
+    SetDeck {
+        seed: Option<u64>,
+    },
+    RunGames {
+        n: usize,
+    },
+    GetResults,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "status")]
+pub enum MgmtResponse {
+    Ok,
+    Results {
+        results: Vec<hearts_game::CompletedHeartsGame>,
+    },
+    Error {
+        reason: String,
+    },
+}
+
+/// Parses `"random" | "avoid" | "aggressive" | "ai:<url>" | "mcts:<iterations>" |
+/// "dmc:<samples>[:<rollouts_per_sample>]"` into a `Strategy`, the vocabulary a harness
+/// uses in a `SetPlayer` command. `mcts` is the tree-search ISMCTS player (UCB1
+/// select/expand over `iterations` determinized playouts); `dmc` is the flat
+/// determinized Monte Carlo evaluator, whose rollouts-per-sample count defaults to 1
+/// when omitted.
+pub fn parse_strategy_spec(spec: &str) -> Result<Strategy, String> {
+    if let Some(url) = spec.strip_prefix("ai:") {
+        return Ok(Strategy::AI(AIStrategy::new(url.to_string())));
+    }
+    if let Some(rest) = spec.strip_prefix("mcts:") {
+        let iterations: u32 = rest
+            .parse()
+            .map_err(|_| format!("invalid mcts iteration count: {}", rest))?;
+        return Ok(Strategy::Mcts(hearts_game::MctsStrategy::new(iterations)));
+    }
+    if let Some(rest) = spec.strip_prefix("dmc:") {
+        let mut parts = rest.splitn(2, ':');
+        let samples_spec = parts.next().unwrap_or("");
+        let samples: u32 = samples_spec
+            .parse()
+            .map_err(|_| format!("invalid dmc sample count: {}", samples_spec))?;
+        let rollouts_per_sample: u32 = match parts.next() {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("invalid dmc rollouts-per-sample count: {}", value))?,
+            None => 1,
+        };
+        return Ok(Strategy::DeterminizedMc(
+            hearts_game::DeterminizedMcStrategy::new(samples, rollouts_per_sample),
+        ));
+    }
+
+    match spec {
+        "random" => Ok(Strategy::Random(RandomStrategy)),
+        "avoid" => Ok(Strategy::AvoidPoints(hearts_game::AvoidPointsStrategy)),
+        "aggressive" => Ok(Strategy::Aggressive(AggressiveStrategy)),
+        "my" => Ok(Strategy::My(MyStrategy)),
+        other => Err(format!("unknown strategy spec: {}", other)),
+    }
+}
+
+/// Parses a `"Name:tag,Name:tag,..."` player-table spec (the `--players` CLI option
+/// on `generate-ai-training-data`) into `(name, strategy_tag, Strategy)` triples,
+/// validating that exactly `num_seats` entries are given. Each `tag` uses the same
+/// vocabulary as `parse_strategy_spec`.
+pub fn parse_player_configs(
+    spec: &str,
+    num_seats: usize,
+) -> Result<Vec<(String, String, Strategy)>, String> {
+    let seats: Vec<(String, String, Strategy)> = spec
+        .split(',')
+        .map(|entry| {
+            let (name, tag) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("player entry missing ':' separator: {}", entry))?;
+            let strategy = parse_strategy_spec(tag)?;
+            Ok((name.to_string(), tag.to_string(), strategy))
+        })
+        .collect::<Result<_, String>>()?;
+
+    if seats.len() != num_seats {
+        return Err(format!(
+            "expected {} players, got {}",
+            num_seats,
+            seats.len()
+        ));
+    }
+    Ok(seats)
+}
+
+struct DaemonState {
+    player_configs: Vec<(String, Strategy)>,
+    deck_seed: Option<u64>,
+    last_results: Vec<hearts_game::CompletedHeartsGame>,
+}
+
+impl DaemonState {
+    fn new() -> Self {
+        Self {
+            player_configs: vec![
+                ("Alice".to_string(), Strategy::Random(RandomStrategy)),
+                ("Bob".to_string(), Strategy::Random(RandomStrategy)),
+                ("Charlie".to_string(), Strategy::AvoidPoints(hearts_game::AvoidPointsStrategy)),
+                ("David".to_string(), Strategy::Aggressive(AggressiveStrategy)),
+            ],
+            deck_seed: None,
+            last_results: Vec::new(),
+        }
+    }
+
+    fn handle(&mut self, command: MgmtCommand) -> MgmtResponse {
+        match command {
+            MgmtCommand::SetPlayer {
+                seat,
+                name,
+                strategy_spec,
+            } => match parse_strategy_spec(&strategy_spec) {
+                Ok(strategy) => {
+                    if seat >= self.player_configs.len() {
+                        return MgmtResponse::Error {
+                            reason: format!("seat {} out of range", seat),
+                        };
+                    }
+                    self.player_configs[seat] = (name, strategy);
+                    MgmtResponse::Ok
+                }
+                Err(reason) => MgmtResponse::Error { reason },
+            },
+            MgmtCommand::SetDeck { seed } => {
+                self.deck_seed = seed;
+                MgmtResponse::Ok
+            }
+            MgmtCommand::RunGames { n } => {
+                let configs: Vec<(&str, Strategy)> = self
+                    .player_configs
+                    .iter()
+                    .map(|(name, strategy)| (name.as_str(), strategy.clone()))
+                    .collect();
+
+                self.last_results = match self.deck_seed {
+                    Some(seed) => generate_with_deck(n, &configs, Deck::new(Some(seed))),
+                    None => generate_with_random_decks(n, &configs),
+                };
+                MgmtResponse::Ok
+            }
+            MgmtCommand::GetResults => MgmtResponse::Results {
+                results: self.last_results.clone(),
+            },
+        }
+    }
+}
+
+fn read_frame(stream: &mut BufReader<&UnixStream>) -> std::io::Result<Vec<u8>> {
+    let mut len_bytes = [0u8; 4];
+    stream.read_exact(&mut len_bytes)?;
+    let len = u32::from_be_bytes(len_bytes) as usize;
+    let mut buf = vec![0u8; len];
+    stream.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn write_frame(stream: &mut UnixStream, payload: &[u8]) -> std::io::Result<()> {
+    stream.write_all(&(payload.len() as u32).to_be_bytes())?;
+    stream.write_all(payload)
+}
+
+/// Run a Unix-socket daemon that accepts framed `MgmtCommand`s and reconfigures the
+/// simulator in-place, so a harness can drive many batches without recompiling.
+pub fn run_daemon(socket_path: &std::path::Path) {
+    let _ = std::fs::remove_file(socket_path);
+    let listener = UnixListener::bind(socket_path).expect("failed to bind mgmt socket");
+    let state = Arc::new(Mutex::new(DaemonState::new()));
+
+    println!("Mgmt daemon listening on {}", socket_path.display());
+    for incoming in listener.incoming() {
+        let Ok(mut stream) = incoming else { continue };
+        let state = Arc::clone(&state);
+        let read_stream = stream.try_clone().expect("failed to clone socket");
+        let mut reader = BufReader::new(&read_stream);
+
+        loop {
+            let frame = match read_frame(&mut reader) {
+                Ok(frame) => frame,
+                Err(_) => break,
+            };
+            let response = match serde_json::from_slice::<MgmtCommand>(&frame) {
+                Ok(command) => state.lock().unwrap().handle(command),
+                Err(err) => MgmtResponse::Error {
+                    reason: err.to_string(),
+                },
+            };
+            let payload = serde_json::to_vec(&response).expect("failed to serialize response");
+            if write_frame(&mut stream, &payload).is_err() {
+                break;
+            }
+        }
+    }
+}
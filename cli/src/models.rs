@@ -1,7 +1,46 @@
-use serde::Serialize;
+use hearts_game::Card;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Serialize, Clone)]
-pub struct CompactCard(pub char, pub u8);
+/// A card packed into a single byte: suit in the low 2 bits, rank in the rest
+/// (`byte = (rank << 2) | suit_code`). Halves the per-card payload of the old
+/// `(char, u8)` encoding in the MessagePack training files, which dominate disk for
+/// large `num_games`.
+#[derive(Clone, Copy)]
+pub struct CompactCard(u8);
+
+const SUITS: [char; 4] = ['C', 'D', 'S', 'H'];
+
+impl CompactCard {
+    pub fn suit(&self) -> char {
+        SUITS[(self.0 & 3) as usize]
+    }
+
+    pub fn rank(&self) -> u8 {
+        self.0 >> 2
+    }
+}
+
+impl From<Card> for CompactCard {
+    fn from(card: Card) -> Self {
+        let suit_code = SUITS
+            .iter()
+            .position(|&s| s == card.suit)
+            .expect("card suit is always one of C/D/S/H") as u8;
+        CompactCard((card.rank << 2) | suit_code)
+    }
+}
+
+impl Serialize for CompactCard {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompactCard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(CompactCard(u8::deserialize(deserializer)?))
+    }
+}
 
 #[derive(Clone, Serialize)]
 pub struct CompactTrick {
@@ -25,3 +64,40 @@ pub struct CompactTrainingData {
     pub player_hand: Vec<CompactCard>,
     pub played_card: CompactCard,
 }
+
+/// One resolved seat in a training run's player table: the seat name plus the raw
+/// strategy tag (e.g. `"dmc:200:3"`) it was parsed from, so the run can be repeated
+/// later from the `--players` spec alone.
+#[derive(Clone, Serialize)]
+pub struct SeatConfig {
+    pub name: String,
+    pub strategy_tag: String,
+}
+
+/// A training-data run's saved output: the examples themselves, plus enough of the
+/// seeding and seating to regenerate the exact same `CompletedHeartsGame` set and
+/// re-extract training data after a filter change.
+#[derive(Clone, Serialize)]
+pub struct TrainingDataset {
+    pub seed: Option<u64>,
+    pub game_seeds: Vec<Option<u64>>,
+    pub seating: Vec<SeatConfig>,
+    pub examples: Vec<CompactTrainingData>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_card_roundtrips_every_card() {
+        for &suit in &SUITS {
+            for rank in 2..=14u8 {
+                let card = Card::new(suit, rank);
+                let compact = CompactCard::from(card);
+                assert_eq!(compact.suit(), suit);
+                assert_eq!(compact.rank(), rank);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use hearts_game::{Card, CompletedHeartsGame};
+use serde::{Deserialize, Serialize};
+
+/// A starting-hand card paired with its index in the shuffled deck at deal time, so a
+/// viewer can show the deal in its original order (mirroring the deck-order
+/// annotations of a hanab.live-style replay).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DealtCard {
+    pub card: Card,
+    pub deck_index: usize,
+}
+
+/// A single card played during the hand, carrying enough of its trick's context that
+/// a viewer can render or filter moves without cross-referencing a separate list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayPlay {
+    pub trick: usize,
+    pub player_index: usize,
+    pub card: Card,
+    pub lead_suit: char,
+    pub trick_winner: usize,
+    pub points: u8,
+}
+
+/// A self-contained record of a finished game that an external viewer can
+/// reconstruct and step through without re-running any game logic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+    pub seats: Vec<String>,
+    pub strategies: Vec<String>,
+    pub initial_hands: Vec<Vec<DealtCard>>,
+    pub plays: Vec<ReplayPlay>,
+}
+
+impl Replay {
+    pub fn from_game(game: &CompletedHeartsGame) -> Self {
+        let seats = game.players.iter().map(|p| p.name.clone()).collect();
+        let strategies = game.players.iter().map(|p| p.strategy.clone()).collect();
+        let initial_hands = game
+            .players
+            .iter()
+            .map(|p| {
+                p.initial_hand
+                    .iter()
+                    .zip(p.deal_order.iter())
+                    .map(|(&card, &deck_index)| DealtCard { card, deck_index })
+                    .collect()
+            })
+            .collect();
+
+        let mut plays = Vec::new();
+        for (trick_index, trick) in game.previous_tricks.iter().enumerate() {
+            let lead_suit = trick.lead_suit();
+            let num_players = trick.cards.len();
+            for p in 0..num_players {
+                let player_index = (trick.first_player_index + p) % num_players;
+                plays.push(ReplayPlay {
+                    trick: trick_index,
+                    player_index,
+                    card: trick.cards[player_index],
+                    lead_suit,
+                    trick_winner: trick.winner_index,
+                    points: trick.score,
+                });
+            }
+        }
+
+        Replay {
+            seats,
+            strategies,
+            initial_hands,
+            plays,
+        }
+    }
+
+    /// Re-derive the set of cards still in each seat's hand immediately before `ply`
+    /// plays are applied, so a viewer can scrub forward/backward through the game
+    /// without recomputing legality from scratch.
+    pub fn hands_at_ply(&self, ply: usize) -> Vec<Vec<Card>> {
+        let mut hands: Vec<Vec<Card>> = self
+            .initial_hands
+            .iter()
+            .map(|hand| hand.iter().map(|dealt| dealt.card).collect())
+            .collect();
+        for play in self.plays.iter().take(ply) {
+            if let Some(hand) = hands.get_mut(play.player_index) {
+                hand.retain(|c| *c != play.card);
+            }
+        }
+        hands
+    }
+}
+
+pub fn save_replay(path: &std::path::Path, game: &CompletedHeartsGame) {
+    let replay = Replay::from_game(game);
+    let file = std::fs::File::create(path).expect("Failed to create replay file");
+    let writer = std::io::BufWriter::new(file);
+    serde_json::to_writer_pretty(writer, &replay).expect("Failed to write replay JSON");
+}
+
+pub fn save_replays(dir: &std::path::Path, games: &[CompletedHeartsGame]) {
+    std::fs::create_dir_all(dir).expect("Failed to create replay directory");
+    for (index, game) in games.iter().enumerate() {
+        let path = dir.join(format!("replay_{}.json", index));
+        save_replay(&path, game);
+    }
+}
@@ -1,6 +1,89 @@
 use hearts_game::CompletedHeartsGame;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Per-strategy aggregate that can be folded incrementally, game by game, instead
+/// of requiring the whole `Vec<CompletedHeartsGame>` to be buffered in memory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StrategyAggregate {
+    pub games_played: u32,
+    pub games_won: u32,
+    pub total_points: u32,
+    pub shoot_the_moon_count: u32,
+}
+
+impl StrategyAggregate {
+    pub fn average_points(&self) -> f64 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            self.total_points as f64 / self.games_played as f64
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            self.games_won as f64 / self.games_played as f64 * 100.0
+        }
+    }
+}
+
+/// Incrementally mergeable statistics accumulator, keyed by strategy name. Workers
+/// can each build a local `Stats` and fold them together with `merge`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Stats {
+    pub games: u32,
+    pub per_strategy: HashMap<String, StrategyAggregate>,
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_game(game: &CompletedHeartsGame) -> Self {
+        let mut stats = Stats::new();
+        stats.add_game(game);
+        stats
+    }
+
+    pub fn add_game(&mut self, game: &CompletedHeartsGame) {
+        self.games += 1;
+        let moon_shot = game.players.iter().any(|p| p.score == 26);
+
+        for (index, player) in game.players.iter().enumerate() {
+            let aggregate = self.per_strategy.entry(player.strategy.clone()).or_default();
+            aggregate.games_played += 1;
+            aggregate.total_points += player.score as u32;
+            if index == game.winner_index {
+                aggregate.games_won += 1;
+            }
+            if moon_shot && player.score == 26 {
+                aggregate.shoot_the_moon_count += 1;
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.games += other.games;
+        for (strategy, aggregate) in &other.per_strategy {
+            let entry = self.per_strategy.entry(strategy.clone()).or_default();
+            entry.games_played += aggregate.games_played;
+            entry.games_won += aggregate.games_won;
+            entry.total_points += aggregate.total_points;
+            entry.shoot_the_moon_count += aggregate.shoot_the_moon_count;
+        }
+    }
+}
+
+impl std::ops::AddAssign<&Stats> for Stats {
+    fn add_assign(&mut self, other: &Stats) {
+        self.merge(other);
+    }
+}
+
 pub fn display_statistics(games: &[CompletedHeartsGame]) {
     let mut total_scores: HashMap<(&String, &String), u32> = HashMap::new();
     let mut total_wins = HashMap::new();
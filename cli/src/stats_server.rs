@@ -0,0 +1,46 @@
+use crate::stats::Stats;
+use axum::{
+    extract::State,
+    routing::{get, post},
+    Json, Router,
+};
+use hearts_game::CompletedHeartsGame;
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+
+type SharedStats = Arc<RwLock<Stats>>;
+
+/// Run an HTTP server that simulation workers can stream `CompletedHeartsGame`s
+/// into: `POST /game` folds one game into the shared running total, `GET /stats`
+/// returns the current aggregate, so many workers never need to buffer every
+/// game in memory themselves.
+pub async fn run(addr: SocketAddr) {
+    let shared: SharedStats = Arc::new(RwLock::new(Stats::new()));
+
+    let app = Router::new()
+        .route("/game", post(post_game))
+        .route("/stats", get(get_stats))
+        .with_state(shared);
+
+    println!("Stats ingest server listening on {}", addr);
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .expect("failed to bind stats server");
+    axum::serve(listener, app)
+        .await
+        .expect("stats server crashed");
+}
+
+async fn post_game(
+    State(shared): State<SharedStats>,
+    Json(game): Json<CompletedHeartsGame>,
+) -> Json<Stats> {
+    let mut stats = shared.write().unwrap();
+    stats.add_game(&game);
+    Json(stats.clone())
+}
+
+async fn get_stats(State(shared): State<SharedStats>) -> Json<Stats> {
+    let stats = shared.read().unwrap();
+    Json(stats.clone())
+}
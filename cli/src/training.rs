@@ -1,33 +1,49 @@
 use crate::models::CompactCard;
 use chrono::Utc;
-use hearts_game::{
-    AggressiveStrategy, AvoidPointsStrategy, Card, CompletedHeartsGame, HeartsGame, RandomStrategy,
-    Strategy, Trick,
-};
+use hearts_game::{Card, CompletedHeartsGame, HeartsGame, Strategy, Trick};
 use rmp_serde;
 use std::fs::{self, File};
 use std::io::BufWriter;
 use std::path::PathBuf;
 use std::time::Instant;
 
+use crate::analysis;
 use crate::game_moves_filter::GameMovesFilter;
+use crate::mgmt::parse_player_configs;
 
-use crate::models::{CompactCompletedTrick, CompactTrainingData, CompactTrick};
+use crate::models::{
+    CompactCompletedTrick, CompactTrainingData, CompactTrick, SeatConfig, TrainingDataset,
+};
 
-pub fn generate_training_data(num_games: usize, save_games: bool, save_as_json: bool) {
+/// The player table used when `--players` is omitted, matching the mix this command
+/// shipped with before seating became configurable.
+const DEFAULT_PLAYERS: &str = "Alice:random,Bob:random,Charlie:avoid,David:aggressive";
+
+pub fn generate_training_data(
+    num_games: usize,
+    save_games: bool,
+    save_as_json: bool,
+    save_replay: bool,
+    seed: Option<u64>,
+    players: Option<String>,
+) {
     let start = Instant::now();
     let mut training_data = Vec::new();
     let mut all_game_results = Vec::with_capacity(num_games);
+    let mut game_seeds = Vec::with_capacity(num_games);
+
+    let seating = parse_player_configs(players.as_deref().unwrap_or(DEFAULT_PLAYERS), 4)
+        .expect("invalid --players spec");
+    let player_configs: Vec<(&str, Strategy)> = seating
+        .iter()
+        .map(|(name, _, strategy)| (name.as_str(), strategy.clone()))
+        .collect();
 
-    let player_configs = [
-        ("Alice", Strategy::Random(RandomStrategy)),
-        ("Bob", Strategy::Random(RandomStrategy)),
-        ("Charlie", Strategy::AvoidPoints(AvoidPointsStrategy)),
-        ("David", Strategy::Aggressive(AggressiveStrategy)),
-    ];
+    for game_index in 0..num_games {
+        let game_seed = seed.map(|base_seed| base_seed.wrapping_add(game_index as u64));
+        game_seeds.push(game_seed);
 
-    for _ in 0..num_games {
-        let mut game = HeartsGame::new(&player_configs);
+        let mut game = HeartsGame::new_seeded(&player_configs, game_seed);
         game.play_game();
         let completed_game = game.completed_game();
         training_data.extend(extract_training_data(&completed_game));
@@ -38,6 +54,19 @@ pub fn generate_training_data(num_games: usize, save_games: bool, save_as_json:
     let total_training_moves = training_data.len();
     let excluded_moves = total_moves - total_training_moves;
 
+    let dataset = TrainingDataset {
+        seed,
+        game_seeds,
+        seating: seating
+            .iter()
+            .map(|(name, tag, _)| SeatConfig {
+                name: name.clone(),
+                strategy_tag: tag.clone(),
+            })
+            .collect(),
+        examples: training_data,
+    };
+
     // Create data directory if it doesn't exist
     fs::create_dir_all("data").expect("Failed to create data directory");
 
@@ -48,14 +77,14 @@ pub fn generate_training_data(num_games: usize, save_games: bool, save_as_json:
 
         let file = File::create(&filepath).expect("Failed to create file");
         let mut writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(&mut writer, &training_data).expect("Failed to write JSON");
+        serde_json::to_writer_pretty(&mut writer, &dataset).expect("Failed to write JSON");
         println!("Training data saved to: {}", filepath.display());
     }
     let filename = format!("training_data_{}_{}_games.msgpack", timestamp, num_games);
     let filepath = PathBuf::from("data").join(filename);
     let file = File::create(&filepath).expect("Failed to create file");
     let mut writer = BufWriter::new(file);
-    rmp_serde::encode::write(&mut writer, &training_data).expect("Failed to write MessagePack");
+    rmp_serde::encode::write(&mut writer, &dataset).expect("Failed to write MessagePack");
 
     // Save game results if requested
     if save_games {
@@ -79,6 +108,22 @@ pub fn generate_training_data(num_games: usize, save_games: bool, save_as_json:
         }
     }
 
+    if save_replay {
+        crate::replay::save_replays(&PathBuf::from("data/replays"), &all_game_results);
+        println!("Replays saved to: data/replays");
+    }
+
+    let analysis = analysis::analyze(&all_game_results);
+    analysis::print_summary(&analysis);
+    if save_games {
+        let filename = format!("analysis_{}_{}_games.json", timestamp, num_games);
+        let filepath = PathBuf::from("data").join(filename);
+        let file = File::create(&filepath).expect("Failed to create file");
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(&mut writer, &analysis).expect("Failed to write JSON");
+        println!("Strategy analysis saved to: {}", filepath.display());
+    }
+
     let duration = start.elapsed();
     println!(
         "\nTime to generate and save training data for {} games: {:?}",
@@ -91,7 +136,7 @@ pub fn generate_training_data(num_games: usize, save_games: bool, save_as_json:
         excluded_moves,
         (excluded_moves as f64 / total_moves as f64) * 100.0
     );
-    println!("Training examples generated: {}", training_data.len());
+    println!("Training examples generated: {}", dataset.examples.len());
 }
 
 fn extract_training_data(completed_game: &CompletedHeartsGame) -> Vec<CompactTrainingData> {
@@ -111,8 +156,10 @@ fn extract_training_data(completed_game: &CompletedHeartsGame) -> Vec<CompactTra
         let mut current_trick = Trick::new();
         current_trick.first_player_index = trick.first_player_index;
 
-        for (p, trick_card) in trick.cards_starting_first_player().iter().enumerate() {
-            let player_index = (trick.first_player_index + p) % 4;
+        let num_players = trick.cards.len();
+        for p in 0..num_players {
+            let player_index = (trick.first_player_index + p) % num_players;
+            let trick_card = &trick.cards[player_index];
             let card_idx = hands[player_index]
                 .iter()
                 .position(|c| c == trick_card)
@@ -127,32 +174,30 @@ fn extract_training_data(completed_game: &CompletedHeartsGame) -> Vec<CompactTra
                         cards: current_trick
                             .cards
                             .iter()
-                            .map(|c| c.map(|c| CompactCard(c.suit, c.rank)))
+                            .map(|c| c.map(CompactCard::from))
                             .collect(),
-                        first_player_index: trick.first_player_index,
+                        first_player: trick.first_player_index,
                     },
                     current_player_index: player_index,
                     player_hand: hands[player_index]
                         .iter()
-                        .map(|c| CompactCard(c.suit, c.rank))
+                        .map(|c| CompactCard::from(*c))
                         .collect(),
-                    played_card: CompactCard(trick_card.suit, trick_card.rank),
+                    played_card: CompactCard::from(*trick_card),
                 };
                 training_data.push(training_item);
             }
-
-            current_trick.add_card(trick_card.clone(), player_index);
         }
 
         previous_tricks.push(CompactCompletedTrick {
             cards: trick
                 .cards
                 .iter()
-                .map(|c| CompactCard(c.suit, c.rank))
+                .map(|c| CompactCard::from(*c))
                 .collect(),
             winner: trick.winner_index,
             points: trick.score,
-            first_player_index: trick.first_player_index,
+            first_player: trick.first_player_index,
         });
     }
     training_data
@@ -29,13 +29,24 @@ impl Deck {
     }
 
     pub fn deal(&mut self, num_players: usize) -> Vec<Vec<Card>> {
-        let mut hands: Vec<Vec<Card>> = vec![Vec::new(); num_players];
+        self.deal_with_deck_order(num_players)
+            .into_iter()
+            .map(|hand| hand.into_iter().map(|(card, _)| card).collect())
+            .collect()
+    }
+
+    /// Like `deal`, but pairs each card with its index in the shuffled deck at deal
+    /// time, so a replay can show the deal in its original order.
+    pub fn deal_with_deck_order(&mut self, num_players: usize) -> Vec<Vec<(Card, usize)>> {
+        let mut hands: Vec<Vec<(Card, usize)>> = vec![Vec::new(); num_players];
 
         for (i, card) in self.cards.drain(..).enumerate() {
-            hands[i % num_players].push(card);
+            hands[i % num_players].push((card, i));
         }
 
-        hands.iter_mut().for_each(|hand| hand.sort());
+        hands
+            .iter_mut()
+            .for_each(|hand| hand.sort_by_key(|(card, _)| *card));
         hands
     }
 
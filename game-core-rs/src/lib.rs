@@ -0,0 +1,16 @@
+mod deck;
+mod game;
+mod models;
+mod player;
+pub mod server;
+mod strategy;
+
+pub use deck::Deck;
+pub use game::HeartsGame;
+pub use models::{Card, CompletedHeartsGame, CompletedTrick, GameState, Trick};
+pub use player::{Player, PlayerInfo};
+pub use server::{GameServer, RemoteStrategy, SeatStatus};
+pub use strategy::{
+    AIStrategy, AggressiveStrategy, AvoidPointsStrategy, DeterminizedMcStrategy, MctsStrategy,
+    MyStrategy, RandomStrategy, Strategy,
+};
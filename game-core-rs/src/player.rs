@@ -0,0 +1,73 @@
+use crate::models::{Card, GameState};
+use crate::strategy::Strategy;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PlayerInfo {
+    pub name: String,
+    pub initial_hand: Vec<Card>,
+    /// Each `initial_hand` card's index in the shuffled deck at deal time, aligned
+    /// positionally with `initial_hand`. Empty when the hand wasn't dealt from a
+    /// tracked deck (e.g. a strategy's internal rollouts).
+    pub deal_order: Vec<usize>,
+    pub score: u8,
+    pub strategy: String,
+}
+
+#[derive(Clone)]
+pub struct Player {
+    pub name: String,
+    pub initial_hand: Vec<Card>,
+    pub deal_order: Vec<usize>,
+    pub hand: Vec<Card>,
+    pub score: u8,
+    pub strategy: Strategy,
+}
+
+impl Player {
+    pub fn new(name: &str, hand: Vec<Card>, strategy: Strategy) -> Self {
+        Self {
+            name: name.to_string(),
+            initial_hand: hand.clone(),
+            deal_order: Vec::new(),
+            hand,
+            score: 0,
+            strategy,
+        }
+    }
+
+    /// Like `new`, but takes a hand dealt from a `Deck`, each card paired with its
+    /// index in the shuffled deck, so the deal order can be reconstructed later (e.g.
+    /// for a replay export).
+    pub fn dealt(name: &str, hand: Vec<(Card, usize)>, strategy: Strategy) -> Self {
+        let initial_hand: Vec<Card> = hand.iter().map(|(card, _)| *card).collect();
+        let deal_order: Vec<usize> = hand.iter().map(|(_, index)| *index).collect();
+        Self {
+            name: name.to_string(),
+            hand: initial_hand.clone(),
+            initial_hand,
+            deal_order,
+            score: 0,
+            strategy,
+        }
+    }
+
+    pub fn play_card(&mut self, valid_moves: &[Card], game_state: Option<GameState>) -> Card {
+        let chosen_card = self.strategy.choose_card(valid_moves, game_state);
+        self.hand.retain(|c| *c != chosen_card);
+        chosen_card
+    }
+
+    pub fn strategy_name(&self) -> &'static str {
+        match self.strategy {
+            Strategy::Random(_) => "Random",
+            Strategy::AvoidPoints(_) => "Avoid Points",
+            Strategy::Aggressive(_) => "Aggressive",
+            Strategy::AI(_) => "AI",
+            Strategy::My(_) => "My Strategy",
+            Strategy::Mcts(_) => "Mcts",
+            Strategy::DeterminizedMc(_) => "DeterminizedMc",
+            Strategy::Remote(_) => "Remote",
+        }
+    }
+}
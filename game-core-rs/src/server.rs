@@ -0,0 +1,227 @@
+use crate::models::{Card, GameState};
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Messages a connected client can send over the websocket.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub enum ClientMessage {
+    /// The client is claiming a seat at the table.
+    JoinSeat { seat: usize },
+    /// The client's answer to a `RequestMove`.
+    ChooseCard { card: Card },
+}
+
+/// Messages the server can push to a connected client.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum ServerMessage {
+    Joined { seat: usize },
+    RequestMove {
+        state: GameState,
+        valid_moves: Vec<Card>,
+    },
+    Error { reason: String },
+}
+
+/// Lifecycle of a seat's remote connection, mirroring the status tracking used by
+/// the planet-wars server so a dropped client can reconnect mid-hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatStatus {
+    Waiting,
+    Connected,
+    Reconnecting,
+}
+
+struct PendingMove {
+    valid_moves: Vec<Card>,
+    reply: oneshot::Sender<Card>,
+}
+
+/// Per-seat shared state: connection status and an outstanding move request, if any.
+#[derive(Default)]
+struct SeatState {
+    status: Option<SeatStatus>,
+    outbound: Option<tokio::sync::mpsc::UnboundedSender<ServerMessage>>,
+    pending: Option<PendingMove>,
+}
+
+#[derive(Clone)]
+pub struct GameServer {
+    seats: Arc<Mutex<HashMap<usize, SeatState>>>,
+    move_timeout: Duration,
+}
+
+impl GameServer {
+    pub fn new(move_timeout: Duration) -> Self {
+        Self {
+            seats: Arc::new(Mutex::new(HashMap::new())),
+            move_timeout,
+        }
+    }
+
+    pub fn seat_status(&self, seat: usize) -> SeatStatus {
+        self.seats
+            .lock()
+            .unwrap()
+            .get(&seat)
+            .and_then(|s| s.status)
+            .unwrap_or(SeatStatus::Waiting)
+    }
+
+    pub async fn listen(&self, addr: SocketAddr) -> std::io::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                if let Err(err) = server.handle_connection(stream).await {
+                    eprintln!("remote player connection ended: {}", err);
+                }
+            });
+        }
+    }
+
+    async fn handle_connection(
+        &self,
+        stream: TcpStream,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let ws_stream = tokio_tungstenite::accept_async(stream).await?;
+        let (mut write, mut read) = ws_stream.split();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<ServerMessage>();
+
+        let mut seat: Option<usize> = None;
+
+        loop {
+            tokio::select! {
+                outgoing = rx.recv() => {
+                    match outgoing {
+                        Some(msg) => write.send(Message::Text(serde_json::to_string(&msg)?)).await?,
+                        None => break,
+                    }
+                }
+                incoming = read.next() => {
+                    match incoming {
+                        Some(Ok(Message::Text(text))) => {
+                            let parsed: ClientMessage = serde_json::from_str(&text)?;
+                            self.handle_client_message(parsed, &mut seat, &tx)?;
+                        }
+                        Some(Ok(Message::Close(_))) | None => break,
+                        Some(Err(_)) => break,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        if let Some(seat) = seat {
+            self.mark_status(seat, SeatStatus::Reconnecting);
+        }
+        Ok(())
+    }
+
+    fn handle_client_message(
+        &self,
+        message: ClientMessage,
+        current_seat: &mut Option<usize>,
+        tx: &tokio::sync::mpsc::UnboundedSender<ServerMessage>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match message {
+            ClientMessage::JoinSeat { seat } => {
+                *current_seat = Some(seat);
+                let mut seats = self.seats.lock().unwrap();
+                let entry = seats.entry(seat).or_default();
+                entry.status = Some(SeatStatus::Connected);
+                entry.outbound = Some(tx.clone());
+                tx.send(ServerMessage::Joined { seat })?;
+            }
+            ClientMessage::ChooseCard { card } => {
+                if let Some(seat) = current_seat {
+                    let mut seats = self.seats.lock().unwrap();
+                    if let Some(state) = seats.get_mut(seat) {
+                        if let Some(pending) = state.pending.take() {
+                            let _ = pending.reply.send(card);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn mark_status(&self, seat: usize, status: SeatStatus) {
+        let mut seats = self.seats.lock().unwrap();
+        seats.entry(seat).or_default().status = Some(status);
+    }
+
+    /// Ask the remote player at `seat` to choose a card, falling back to the first
+    /// valid move (mirroring `AIStrategy`'s fallback) if they don't answer in time.
+    pub fn request_move(
+        &self,
+        seat: usize,
+        state: GameState,
+        valid_moves: Vec<Card>,
+    ) -> Card {
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut seats = self.seats.lock().unwrap();
+            let seat_state = seats.entry(seat).or_default();
+            match &seat_state.outbound {
+                Some(outbound) => {
+                    seat_state.pending = Some(PendingMove {
+                        valid_moves: valid_moves.clone(),
+                        reply: tx,
+                    });
+                    let _ = outbound.send(ServerMessage::RequestMove {
+                        state,
+                        valid_moves: valid_moves.clone(),
+                    });
+                }
+                None => return valid_moves[0],
+            }
+        }
+
+        let timeout = self.move_timeout;
+        let handle = tokio::runtime::Handle::try_current();
+        match handle {
+            Ok(handle) => handle
+                .block_on(async move { tokio::time::timeout(timeout, rx).await })
+                .ok()
+                .and_then(|r| r.ok())
+                .unwrap_or(valid_moves[0]),
+            Err(_) => valid_moves[0],
+        }
+    }
+}
+
+/// `PlayingStrategy` backed by a live websocket connection: blocks on a round-trip
+/// to the connected client, falling back to the first valid move on timeout.
+#[derive(Clone)]
+pub struct RemoteStrategy {
+    pub seat: usize,
+    pub server: GameServer,
+}
+
+impl RemoteStrategy {
+    pub fn new(seat: usize, server: GameServer) -> Self {
+        Self { seat, server }
+    }
+}
+
+impl crate::strategy::PlayingStrategy for RemoteStrategy {
+    fn choose_card(&self, valid_moves: &[Card], game_state: Option<GameState>) -> Card {
+        let state = match game_state {
+            Some(state) => state,
+            None => return valid_moves[0],
+        };
+        self.server
+            .request_move(self.seat, state, valid_moves.to_vec())
+    }
+}
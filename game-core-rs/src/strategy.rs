@@ -340,6 +340,9 @@ pub enum Strategy {
     Aggressive(AggressiveStrategy),
     AI(AIStrategy),
     My(MyStrategy),
+    Mcts(MctsStrategy),
+    DeterminizedMc(DeterminizedMcStrategy),
+    Remote(crate::server::RemoteStrategy),
 }
 
 impl Strategy {
@@ -350,6 +353,9 @@ impl Strategy {
             Strategy::Aggressive(s) => s.choose_card(valid_moves, game_state),
             Strategy::AI(s) => s.choose_card(valid_moves, game_state),
             Strategy::My(s) => s.choose_card(valid_moves, game_state),
+            Strategy::Mcts(s) => s.choose_card(valid_moves, game_state),
+            Strategy::DeterminizedMc(s) => s.choose_card(valid_moves, game_state),
+            Strategy::Remote(s) => s.choose_card(valid_moves, game_state),
         }
     }
 
@@ -360,6 +366,441 @@ impl Strategy {
             Strategy::Aggressive(_) => false,
             Strategy::AI(_) => true,
             Strategy::My(_) => true,
+            Strategy::Mcts(_) => true,
+            Strategy::DeterminizedMc(_) => true,
+            Strategy::Remote(_) => true,
         }
     }
 }
+
+/// Determinized Monte Carlo Hearts player: for each legal card, samples `samples`
+/// plausible full deals of the unseen cards among the other three players (respecting
+/// each opponent's known remaining hand size and the void suits inferred from
+/// `previous_tricks`), plays `rollouts_per_sample` short heuristic rollouts to the end
+/// of the round from each deal, and picks the card with the lowest mean penalty points
+/// for the acting player across every sample and rollout. Falls back to
+/// `AvoidPointsStrategy` if no legal sample can be constructed for any candidate.
+///
+/// Distinct from `MctsStrategy`: this evaluates each candidate move directly by
+/// averaging flat rollouts, with no search tree or per-action visit counts.
+#[derive(Clone)]
+pub struct DeterminizedMcStrategy {
+    pub samples: u32,
+    pub rollouts_per_sample: u32,
+}
+
+impl DeterminizedMcStrategy {
+    pub fn new(samples: u32, rollouts_per_sample: u32) -> Self {
+        Self {
+            samples,
+            rollouts_per_sample,
+        }
+    }
+}
+
+/// A fully-observable determinization of the hidden hands, used as the world a
+/// rollout plays out in.
+#[derive(Clone)]
+struct Determinization {
+    hands: [Vec<Card>; 4],
+    tricks: Vec<crate::models::Trick>,
+    current_trick: crate::models::Trick,
+    hearts_broken: bool,
+}
+
+fn infer_void_suits(game_state: &GameState) -> [Vec<char>; 4] {
+    let mut voids: [Vec<char>; 4] = Default::default();
+    for trick in &game_state.previous_tricks {
+        let lead_suit = trick.lead_suit();
+        for (player_index, card) in trick.cards.iter().enumerate() {
+            if card.suit != lead_suit && !voids[player_index].contains(&lead_suit) {
+                voids[player_index].push(lead_suit);
+            }
+        }
+    }
+    // The trick in progress can also reveal a void before it's completed.
+    if let Some(lead_suit) = game_state.current_trick.lead_suit() {
+        for (player_index, card) in game_state.current_trick.cards.iter().enumerate() {
+            if let Some(card) = card {
+                if card.suit != lead_suit && !voids[player_index].contains(&lead_suit) {
+                    voids[player_index].push(lead_suit);
+                }
+            }
+        }
+    }
+    voids
+}
+
+/// How many cards each seat still holds. Every completed trick takes exactly one card
+/// from every seat, so only the partially-played current trick can make seats differ.
+fn hand_sizes(game_state: &GameState) -> [usize; 4] {
+    let mut played = [game_state.previous_tricks.len(); 4];
+    for (player, card) in game_state.current_trick.cards.iter().enumerate() {
+        if card.is_some() {
+            played[player] += 1;
+        }
+    }
+    played.map(|count| 13 - count)
+}
+
+/// `GameState` doesn't carry `hearts_broken` directly, so it's derived from whether
+/// any heart has appeared in a completed trick or the trick in progress.
+fn hearts_broken_so_far(game_state: &GameState) -> bool {
+    game_state
+        .previous_tricks
+        .iter()
+        .flat_map(|trick| trick.cards.iter())
+        .chain(game_state.current_trick.cards.iter().flatten())
+        .any(|card| card.is_hearts())
+}
+
+fn unseen_cards(game_state: &GameState) -> Vec<Card> {
+    let mut seen: Vec<Card> = game_state.player_hand.clone();
+    for trick in &game_state.previous_tricks {
+        seen.extend(trick.cards.iter().cloned());
+    }
+    for card in game_state.current_trick.cards.iter().flatten() {
+        seen.push(*card);
+    }
+
+    ['S', 'H', 'D', 'C']
+        .iter()
+        .flat_map(|&suit| (2..=14).map(move |rank| Card::new(suit, rank)))
+        .filter(|card| !seen.contains(card))
+        .collect()
+}
+
+/// Samples one determinization of the unseen cards, dealing them to the other three
+/// players at random subject to each opponent's known remaining hand size and the
+/// suits they're inferred void in. Returns `None` if those constraints leave some
+/// opponent unable to receive enough cards (the caller should just try another
+/// sample).
+fn determinize(game_state: &GameState, rng: &mut impl rand::Rng) -> Option<Determinization> {
+    use rand::seq::SliceRandom;
+
+    let me = game_state.current_player_index;
+    let voids = infer_void_suits(game_state);
+    let sizes = hand_sizes(game_state);
+    let mut unseen = unseen_cards(game_state);
+    unseen.shuffle(rng);
+
+    let mut hands: [Vec<Card>; 4] = Default::default();
+    hands[me] = game_state.player_hand.clone();
+
+    for card in unseen {
+        let eligible: Vec<usize> = (0..4)
+            .filter(|&p| p != me && hands[p].len() < sizes[p] && !voids[p].contains(&card.suit))
+            .collect();
+        let player = *eligible.choose(rng)?;
+        hands[player].push(card);
+    }
+
+    if (0..4).any(|p| p != me && hands[p].len() != sizes[p]) {
+        return None;
+    }
+
+    Some(Determinization {
+        hands,
+        tricks: game_state
+            .previous_tricks
+            .iter()
+            .map(|t| crate::models::Trick {
+                cards: t.cards.iter().map(|c| Some(*c)).collect(),
+                first_player_index: t.first_player_index,
+            })
+            .collect(),
+        current_trick: game_state.current_trick.clone(),
+        hearts_broken: hearts_broken_so_far(game_state),
+    })
+}
+
+fn legal_moves(det: &Determinization, player: usize) -> Vec<Card> {
+    let hand = &det.hands[player];
+    if det.current_trick.is_first_card() && det.tricks.is_empty() {
+        if let Some(two_clubs) = hand.iter().find(|c| c.is_two_of_clubs()) {
+            return vec![*two_clubs];
+        }
+    }
+
+    if let Some(lead_suit) = det.current_trick.lead_suit() {
+        let same_suit: Vec<Card> = hand.iter().filter(|c| c.suit == lead_suit).copied().collect();
+        if !same_suit.is_empty() {
+            return same_suit;
+        }
+        return hand.clone();
+    }
+
+    if det.tricks.is_empty() {
+        let safe: Vec<Card> = hand.iter().filter(|c| !c.is_penalty()).copied().collect();
+        return if safe.is_empty() { hand.clone() } else { safe };
+    }
+
+    if !det.hearts_broken {
+        let non_hearts: Vec<Card> = hand.iter().filter(|c| !c.is_hearts()).copied().collect();
+        if !non_hearts.is_empty() {
+            return non_hearts;
+        }
+    }
+
+    hand.clone()
+}
+
+fn current_player(det: &Determinization, me: usize) -> usize {
+    let played = det.current_trick.cards.iter().filter(|c| c.is_some()).count();
+    (det.current_trick.first_player_index + played) % 4
+}
+
+fn apply_move(det: &mut Determinization, player: usize, card: Card) {
+    det.hands[player].retain(|c| *c != card);
+    if card.is_hearts() {
+        det.hearts_broken = true;
+    }
+    det.current_trick.add_card(card, player);
+
+    if det.current_trick.is_completed() {
+        let lead_suit = det.current_trick.lead_suit().unwrap();
+        let winner = det
+            .current_trick
+            .cards
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.map(|c| c.suit) == Some(lead_suit))
+            .max_by_key(|(_, c)| c.unwrap().rank)
+            .map(|(i, _)| i)
+            .unwrap();
+        let mut finished = std::mem::replace(&mut det.current_trick, crate::models::Trick::new());
+        finished.first_player_index = winner;
+        det.tricks.push(finished);
+        det.current_trick.first_player_index = winner;
+    }
+}
+
+/// Cheap rollout heuristic: avoid penalties, otherwise play low.
+fn heuristic_move(legal: &[Card]) -> Card {
+    *legal
+        .iter()
+        .min_by_key(|c| if c.is_penalty() { c.rank + 13 } else { c.rank })
+        .unwrap()
+}
+
+fn points_taken(det: &Determinization, player: usize) -> u8 {
+    det.tricks
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| {
+            let lead_suit = t.cards[t.first_player_index].unwrap().suit;
+            t.cards
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.map(|c| c.suit) == Some(lead_suit))
+                .max_by_key(|(_, c)| c.unwrap().rank)
+                .map(|(i, _)| i)
+                == Some(player)
+        })
+        .map(|(_, t)| t.cards.iter().map(|c| c.unwrap().score()).sum::<u8>())
+        .sum()
+}
+
+/// Penalty points the acting player ends up with for the hand, reconciling the
+/// shoot-the-moon rule (26 points flips to 0 for the shooter, 26 for everyone else).
+fn final_points_for(det: &Determinization, me: usize) -> u8 {
+    let totals: [u8; 4] = std::array::from_fn(|player| points_taken(det, player));
+    match totals.iter().position(|&points| points == 26) {
+        Some(shooter) if shooter == me => 0,
+        Some(_) => 26,
+        None => totals[me],
+    }
+}
+
+fn rollout(det: &mut Determinization, me: usize) {
+    while det.tricks.len() < 13 {
+        let player = current_player(det, me);
+        let legal = legal_moves(det, player);
+        let card = heuristic_move(&legal);
+        apply_move(det, player, card);
+    }
+}
+
+impl PlayingStrategy for DeterminizedMcStrategy {
+    fn choose_card(&self, valid_moves: &[Card], game_state: Option<GameState>) -> Card {
+        let game_state = match game_state {
+            Some(state) => state,
+            None => return valid_moves[0],
+        };
+        if valid_moves.len() == 1 {
+            return valid_moves[0];
+        }
+
+        let me = game_state.current_player_index;
+        let mut rng = rand::thread_rng();
+
+        let mut total_points = vec![0f64; valid_moves.len()];
+        let mut trials = vec![0u32; valid_moves.len()];
+
+        for _ in 0..self.samples {
+            let Some(sampled) = determinize(&game_state, &mut rng) else {
+                continue;
+            };
+
+            for (candidate_index, &candidate) in valid_moves.iter().enumerate() {
+                for _ in 0..self.rollouts_per_sample {
+                    let mut det = sampled.clone();
+                    apply_move(&mut det, me, candidate);
+                    rollout(&mut det, me);
+                    total_points[candidate_index] += final_points_for(&det, me) as f64;
+                    trials[candidate_index] += 1;
+                }
+            }
+        }
+
+        valid_moves
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| trials[i] > 0)
+            .min_by(|&(a, _), &(b, _)| {
+                let mean_a = total_points[a] / trials[a] as f64;
+                let mean_b = total_points[b] / trials[b] as f64;
+                mean_a.partial_cmp(&mean_b).unwrap()
+            })
+            .map(|(_, &card)| card)
+            .unwrap_or_else(|| AvoidPointsStrategy.choose_card(valid_moves, Some(game_state)))
+    }
+}
+
+/// Information-Set Monte Carlo Tree Search over determinizations of the hidden hands.
+///
+/// Each iteration deals the unseen cards to the other three players consistent with
+/// suits they are known to be void in (inferred from previous tricks), then runs one
+/// MCTS pass (UCB1 select/expand, heuristic rollout, backprop) on that fully-observable
+/// determinization. The root action with the most visits across all iterations wins.
+/// Falls back to `AvoidPointsStrategy` if every determinization attempt fails.
+#[derive(Clone)]
+pub struct MctsStrategy {
+    pub iterations: u32,
+    pub exploration: f64,
+}
+
+impl MctsStrategy {
+    pub fn new(iterations: u32) -> Self {
+        Self {
+            iterations,
+            exploration: std::f64::consts::SQRT_2,
+        }
+    }
+}
+
+/// A node in the ISMCTS tree, keyed implicitly by the path taken to reach it.
+/// Stats are per-action so differing legal-move sets across determinizations are
+/// handled naturally: an action simply has no entry until the first iteration that
+/// sees it among the legal moves.
+#[derive(Default)]
+struct McNode {
+    visits: HashMap<Card, u32>,
+    reward: HashMap<Card, f64>,
+    children: HashMap<Card, McNode>,
+}
+
+impl McNode {
+    fn ucb1_select(&mut self, legal: &[Card], parent_visits: u32, c: f64) -> Card {
+        for card in legal {
+            self.visits.entry(*card).or_insert(0);
+            self.reward.entry(*card).or_insert(0.0);
+        }
+
+        legal
+            .iter()
+            .max_by(|a, b| {
+                self.ucb1_value(**a, parent_visits, c)
+                    .partial_cmp(&self.ucb1_value(**b, parent_visits, c))
+                    .unwrap()
+            })
+            .copied()
+            .unwrap()
+    }
+
+    fn ucb1_value(&self, card: Card, parent_visits: u32, c: f64) -> f64 {
+        let visits = *self.visits.get(&card).unwrap_or(&0);
+        if visits == 0 {
+            return f64::INFINITY;
+        }
+        let mean = self.reward.get(&card).copied().unwrap_or(0.0) / visits as f64;
+        mean + c * ((parent_visits.max(1) as f64).ln() / visits as f64).sqrt()
+    }
+
+    fn record(&mut self, card: Card, reward: f64) {
+        *self.visits.entry(card).or_insert(0) += 1;
+        *self.reward.entry(card).or_insert(0.0) += reward;
+    }
+
+    fn best_by_visits(&self) -> Option<Card> {
+        self.visits
+            .iter()
+            .max_by_key(|(_, &visits)| visits)
+            .map(|(card, _)| *card)
+    }
+}
+
+/// The ISMCTS backprop reward: the negative of the acting player's final penalty
+/// points, so UCB1's "maximize value" selection naturally minimizes points taken
+/// (and a shot-the-moon's 0-point result for the shooter is the best reward available).
+fn reward_for(det: &Determinization, me: usize) -> f64 {
+    -(final_points_for(det, me) as f64)
+}
+
+fn mcts_iteration(node: &mut McNode, det: &mut Determinization, me: usize, c: f64) -> f64 {
+    let player = current_player(det, me);
+    let legal = legal_moves(det, player);
+
+    if player != me || det.tricks.len() >= 13 {
+        // Opponents play the rollout heuristic; once in a rollout, fall through entirely.
+        if det.tricks.len() >= 13 {
+            return reward_for(det, me);
+        }
+        let card = heuristic_move(&legal);
+        apply_move(det, player, card);
+        return mcts_iteration(node, det, me, c);
+    }
+
+    let parent_visits: u32 = node.visits.values().sum();
+    let is_new_node = node.visits.values().all(|&v| v == 0);
+    let card = node.ucb1_select(&legal, parent_visits, c);
+
+    apply_move(det, player, card);
+
+    let reward = if is_new_node {
+        rollout(det, me);
+        reward_for(det, me)
+    } else {
+        let child = node.children.entry(card).or_default();
+        mcts_iteration(child, det, me, c)
+    };
+
+    node.record(card, reward);
+    reward
+}
+
+impl PlayingStrategy for MctsStrategy {
+    fn choose_card(&self, valid_moves: &[Card], game_state: Option<GameState>) -> Card {
+        let game_state = match game_state {
+            Some(state) => state,
+            None => return valid_moves[0],
+        };
+        if valid_moves.len() == 1 {
+            return valid_moves[0];
+        }
+
+        let me = game_state.current_player_index;
+        let mut rng = rand::thread_rng();
+        let mut root = McNode::default();
+
+        for _ in 0..self.iterations {
+            let Some(mut det) = determinize(&game_state, &mut rng) else {
+                continue;
+            };
+            mcts_iteration(&mut root, &mut det, me, self.exploration);
+        }
+
+        root.best_by_visits()
+            .unwrap_or_else(|| AvoidPointsStrategy.choose_card(valid_moves, Some(game_state)))
+    }
+}
@@ -5,14 +5,26 @@ pub struct Deck {
     cards: Vec<Card>,
 }
 
+/// A freshly ordered 52-card deck, unshuffled. Exposed so a strategy can enumerate the
+/// full deck when figuring out which cards are still unseen.
+pub fn ordered_deck() -> Vec<Card> {
+    ['S', 'H', 'D', 'C']
+        .iter()
+        .flat_map(|&suit| (2..=14).map(move |rank| Card::new(suit, rank)))
+        .collect()
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Deck {
     pub fn new() -> Self {
-        let cards: Vec<Card> = ['S', 'H', 'D', 'C']
-            .iter()
-            .flat_map(|&suit| (2..=14).map(move |rank| Card::new(suit, rank)))
-            .collect();
-
-        let mut deck = Self { cards };
+        let mut deck = Self {
+            cards: ordered_deck(),
+        };
         deck.shuffle();
         deck
     }
@@ -17,7 +17,7 @@ impl HeartsGame {
         let hands = deck.deal(4);
         let players: Vec<Player> = player_configs
             .iter()
-            .zip(hands.into_iter())
+            .zip(hands)
             .map(|((name, strategy), hand)| Player::new(name, hand, strategy.clone()))
             .collect();
 
@@ -112,7 +112,7 @@ impl HeartsGame {
         hand.to_vec()
     }
 
-    fn determine_trick_winner(cards: &Vec<Card>, first_player: usize) -> usize {
+    fn determine_trick_winner(cards: &[Card], first_player: usize) -> usize {
         let lead_suit = cards[first_player].suit;
         cards
             .iter()
@@ -156,13 +156,17 @@ impl HeartsGame {
         }
     }
 
-    fn complete_trick(&mut self) {
+    /// Scores the just-finished `current_trick`, credits the winner, appends it to
+    /// `tricks`, and resets `current_trick` for the next one. Shared by the live game
+    /// loop and by `PIMCStrategy`'s rollouts, which build a resumed `HeartsGame` on a
+    /// determinized world and drive it with this same bookkeeping.
+    pub(crate) fn complete_trick(&mut self) {
         let first_player_index = self.current_trick.first_player_index;
-        let trick_cards = self
+        let trick_cards: Vec<Card> = self
             .current_trick
             .cards
             .iter()
-            .map(|c| c.unwrap().clone())
+            .map(|c| c.unwrap())
             .collect();
 
         let winner = Self::determine_trick_winner(&trick_cards, first_player_index);
@@ -189,6 +193,7 @@ impl HeartsGame {
             current_trick: self.current_trick.clone(),
             player_hand: self.players[self.current_player_index].hand.clone(),
             current_player_index: self.current_player_index,
+            points_taken: self.players.iter().map(|p| p.score).collect(),
         }
     }
 
@@ -216,6 +221,19 @@ impl HeartsGame {
         while !self.game_is_over() && !self.current_trick.is_completed() {
             self.play_trick();
         }
+        self.apply_shoot_the_moon();
+    }
+
+    /// If one seat captured all 26 penalty points (every heart plus the Queen of
+    /// Spades), inverts that hand's scoring: the shooter scores 0 and everyone else
+    /// takes 26, per the standard "shooting the moon" rule.
+    fn apply_shoot_the_moon(&mut self) {
+        let Some(shooter) = self.players.iter().position(|p| p.score == 26) else {
+            return;
+        };
+        for (i, player) in self.players.iter_mut().enumerate() {
+            player.score = if i == shooter { 0 } else { 26 };
+        }
     }
 
     fn game_is_over(&self) -> bool {
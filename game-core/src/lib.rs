@@ -8,4 +8,6 @@ pub use deck::Deck;
 pub use game::HeartsGame;
 pub use models::{Card, CompletedHeartsGame, CompletedTrick, GameState, Trick};
 pub use player::{Player, PlayerInfo};
-pub use strategy::{AIStrategy, AggressiveStrategy, AvoidPointsStrategy, RandomStrategy, Strategy};
+pub use strategy::{
+    AIStrategy, AggressiveStrategy, AvoidPointsStrategy, PIMCStrategy, RandomStrategy, Strategy,
+};
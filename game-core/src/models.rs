@@ -42,8 +42,8 @@ impl Card {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletedTrick {
     pub cards: Vec<Card>,
-    pub winner: usize,
-    pub points: u8,
+    pub winner_index: usize,
+    pub score: u8,
     pub first_player_index: usize,
 }
 
@@ -63,6 +63,12 @@ pub struct Trick {
     pub first_player_index: usize,
 }
 
+impl Default for Trick {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Trick {
     pub fn new() -> Self {
         Self {
@@ -102,8 +108,12 @@ pub struct CompletedHeartsGame {
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct GameState {
-    pub tricks: Vec<CompletedTrick>,
+    pub previous_tricks: Vec<CompletedTrick>,
     pub current_trick: Trick,
-    pub current_player: usize,
-    pub hearts_broken: bool,
+    pub player_hand: Vec<Card>,
+    pub current_player_index: usize,
+    /// Each player's penalty points taken so far this hand, indexed by player index.
+    /// Lets a strategy notice it (or an opponent) is closing in on all 26 points and
+    /// actually pursue (or block) a moon shot, rather than always just avoiding points.
+    pub points_taken: Vec<u8>,
 }
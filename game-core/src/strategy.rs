@@ -1,4 +1,9 @@
-use crate::models::{Card, GameState};
+use crate::deck::ordered_deck;
+use crate::game::HeartsGame;
+use crate::models::{Card, CompletedTrick, GameState, Trick};
+use crate::player::Player;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
 use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
 
@@ -120,12 +125,223 @@ impl PlayingStrategy for AIStrategy {
     }
 }
 
+/// Inspects completed tricks to find, for each seat, the suits they're known to be
+/// void in (they failed to follow the trick's lead suit), so determinizations never
+/// deal an opponent a suit they've already demonstrably run out of.
+fn infer_void_suits(previous_tricks: &[CompletedTrick]) -> [Vec<char>; 4] {
+    let mut voids: [Vec<char>; 4] = Default::default();
+    for trick in previous_tricks {
+        let lead_suit = trick.lead_suit();
+        for (player, card) in trick.cards.iter().enumerate() {
+            if card.suit != lead_suit && !voids[player].contains(&card.suit) {
+                voids[player].push(card.suit);
+            }
+        }
+    }
+    voids
+}
+
+/// The full deck minus every card already known to be somewhere specific: in our own
+/// hand, already played in `previous_tricks`, or already played in the current trick.
+fn unseen_cards(hand: &[Card], current_trick: &Trick, previous_tricks: &[CompletedTrick]) -> Vec<Card> {
+    let mut seen: Vec<Card> = hand.to_vec();
+    seen.extend(current_trick.cards.iter().flatten().copied());
+    for trick in previous_tricks {
+        seen.extend(trick.cards.iter().copied());
+    }
+    ordered_deck().into_iter().filter(|c| !seen.contains(c)).collect()
+}
+
+/// How many cards each seat still holds. Every completed trick takes exactly one card
+/// from every seat, so only the partially-played current trick can make seats differ.
+fn hand_sizes(previous_tricks: &[CompletedTrick], current_trick: &Trick) -> [usize; 4] {
+    let mut played = [previous_tricks.len(); 4];
+    for (player, card) in current_trick.cards.iter().enumerate() {
+        if card.is_some() {
+            played[player] += 1;
+        }
+    }
+    played.map(|count| 13 - count)
+}
+
+/// Randomly deals `unseen` among every seat but `me`, respecting each seat's inferred
+/// voids and the number of cards they're still known to be holding.
+fn determinize_hands(
+    me: usize,
+    my_hand: Vec<Card>,
+    mut unseen: Vec<Card>,
+    hand_sizes: &[usize; 4],
+    voids: &[Vec<char>; 4],
+) -> [Vec<Card>; 4] {
+    let mut hands: [Vec<Card>; 4] = Default::default();
+    hands[me] = my_hand;
+
+    let mut rng = thread_rng();
+    unseen.shuffle(&mut rng);
+
+    for card in unseen {
+        let eligible: Vec<usize> = (0..4)
+            .filter(|&p| p != me && hands[p].len() < hand_sizes[p] && !voids[p].contains(&card.suit))
+            .collect();
+
+        let chosen = eligible
+            .choose(&mut rng)
+            .copied()
+            .or_else(|| (0..4).find(|&p| p != me && hands[p].len() < hand_sizes[p]));
+
+        if let Some(p) = chosen {
+            hands[p].push(card);
+        }
+    }
+
+    hands
+}
+
+/// Plays one determinized world to completion after forcing `candidate` onto seat
+/// `me`, using `AvoidPointsStrategy` for every seat (including `me`, from the next
+/// decision on) as a cheap rollout policy, and returns `me`'s final total points for
+/// the whole hand. Each seat's score is seeded from `points_taken`, the points it has
+/// actually captured so far, so the rollout's own shoot-the-moon reconciliation (all
+/// 26 points to one seat) reflects the real hand, not just the cards left to play.
+fn simulate_rollout(
+    me: usize,
+    candidate: Card,
+    hands: [Vec<Card>; 4],
+    previous_tricks: Vec<CompletedTrick>,
+    mut current_trick: Trick,
+    mut hearts_broken: bool,
+    points_taken: &[u8],
+) -> u8 {
+    let mut players: Vec<Player> = hands
+        .into_iter()
+        .enumerate()
+        .map(|(seat, hand)| {
+            let mut player = Player::new(
+                &format!("seat{}", seat),
+                hand,
+                Strategy::AvoidPoints(AvoidPointsStrategy),
+            );
+            player.score = points_taken[seat];
+            player
+        })
+        .collect();
+
+    if current_trick.is_first_card() {
+        current_trick.first_player_index = me;
+    }
+    current_trick.add_card(candidate, me);
+    players[me].hand.retain(|c| *c != candidate);
+    if candidate.is_hearts() {
+        hearts_broken = true;
+    }
+
+    let current_player_index = if current_trick.is_completed() { me } else { (me + 1) % 4 };
+
+    let mut game = HeartsGame {
+        players,
+        tricks: previous_tricks,
+        current_trick,
+        current_player_index,
+        hearts_broken,
+    };
+
+    if game.current_trick.is_completed() {
+        game.complete_trick();
+    }
+
+    game.play_game();
+
+    game.players[me].score
+}
+
+/// Determinized playout search (Perfect-Information Monte Carlo): for each candidate
+/// move, runs `worlds` random deals of the unseen cards (honoring inferred voids),
+/// plays each one out with a cheap heuristic (`AvoidPointsStrategy`), and returns the
+/// candidate with the lowest average final hand score for the acting player (correctly
+/// rewarding a real moon-shot attempt, since the rollout's final score already
+/// reflects shoot-the-moon reconciliation). Ties favor the lower-ranked card.
+#[derive(Clone)]
+pub struct PIMCStrategy {
+    pub worlds: usize,
+}
+
+impl PIMCStrategy {
+    pub fn new(worlds: usize) -> Self {
+        Self { worlds }
+    }
+}
+
+impl Default for PIMCStrategy {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl PlayingStrategy for PIMCStrategy {
+    fn choose_card(&self, valid_moves: &[Card], game_state: Option<GameState>) -> Card {
+        if valid_moves.len() == 1 {
+            return valid_moves[0];
+        }
+
+        let game_state = game_state.unwrap();
+        let me = game_state.current_player_index;
+        let hand = &game_state.player_hand;
+        let voids = infer_void_suits(&game_state.previous_tricks);
+        let sizes = hand_sizes(&game_state.previous_tricks, &game_state.current_trick);
+        let hearts_broken = hearts_broken_so_far(&game_state);
+        let unseen = unseen_cards(hand, &game_state.current_trick, &game_state.previous_tricks);
+
+        let mut best_card = valid_moves[0];
+        let mut best_points = f64::MAX;
+
+        for &candidate in valid_moves {
+            let mut remaining_hand = hand.clone();
+            remaining_hand.retain(|c| *c != candidate);
+
+            let mut total_points = 0u32;
+            for _ in 0..self.worlds {
+                let hands = determinize_hands(me, remaining_hand.clone(), unseen.clone(), &sizes, &voids);
+
+                total_points += simulate_rollout(
+                    me,
+                    candidate,
+                    hands,
+                    game_state.previous_tricks.clone(),
+                    game_state.current_trick.clone(),
+                    hearts_broken,
+                    &game_state.points_taken,
+                ) as u32;
+            }
+
+            let average = total_points as f64 / self.worlds as f64;
+            if average < best_points || (average == best_points && candidate.rank < best_card.rank) {
+                best_points = average;
+                best_card = candidate;
+            }
+        }
+
+        best_card
+    }
+}
+
+/// `GameState` doesn't carry `hearts_broken` directly, so it's derived from whether any
+/// heart has appeared in a completed trick or the trick in progress.
+fn hearts_broken_so_far(game_state: &GameState) -> bool {
+    game_state
+        .previous_tricks
+        .iter()
+        .flat_map(|trick| trick.cards.iter())
+        .chain(game_state.current_trick.cards.iter().flatten())
+        .any(|card| card.is_hearts())
+}
+
 #[derive(Clone)]
 pub enum Strategy {
     Random(RandomStrategy),
     AvoidPoints(AvoidPointsStrategy),
     Aggressive(AggressiveStrategy),
     AI(AIStrategy),
+    PIMC(PIMCStrategy),
 }
 
 impl Strategy {
@@ -135,6 +351,7 @@ impl Strategy {
             Strategy::AvoidPoints(s) => s.choose_card(valid_moves, game_state),
             Strategy::Aggressive(s) => s.choose_card(valid_moves, game_state),
             Strategy::AI(s) => s.choose_card(valid_moves, game_state),
+            Strategy::PIMC(s) => s.choose_card(valid_moves, game_state),
         }
     }
 
@@ -144,6 +361,7 @@ impl Strategy {
             Strategy::AvoidPoints(_) => false,
             Strategy::Aggressive(_) => false,
             Strategy::AI(_) => true,
+            Strategy::PIMC(_) => true,
         }
     }
 }
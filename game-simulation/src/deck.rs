@@ -0,0 +1,84 @@
+use crate::card::Card;
+use rand::seq::SliceRandom;
+use rand::{rngs::StdRng, SeedableRng};
+
+#[derive(Clone)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+/// A freshly ordered 52-card deck, unshuffled. Used both to seed `Deck::new` and by
+/// strategies that need to enumerate the full deck (e.g. to derive unseen cards for
+/// determinization).
+pub fn ordered_deck() -> Vec<Card> {
+    ['S', 'H', 'D', 'C']
+        .iter()
+        .flat_map(|&suit| (2..=14).map(move |rank| Card::new(suit, rank)))
+        .collect()
+}
+
+impl Deck {
+    /// Builds a freshly shuffled deck. Passing `Some(seed)` makes the shuffle (and
+    /// therefore the deal) reproducible, so a tournament can replay the same deal
+    /// across seat rotations or across repeated runs.
+    pub fn new(seed: Option<u64>) -> Self {
+        let mut deck = Self {
+            cards: ordered_deck(),
+        };
+        deck.shuffle(seed);
+        deck
+    }
+
+    pub fn shuffle(&mut self, seed: Option<u64>) {
+        match seed {
+            Some(seed_value) => self.cards.shuffle(&mut StdRng::seed_from_u64(seed_value)),
+            None => self.cards.shuffle(&mut rand::thread_rng()),
+        }
+    }
+
+    /// Deals the deck evenly among `num_players` seats (3-6). When 52 doesn't divide
+    /// evenly, the lowest-ranked cards are set aside first so every hand still ends up
+    /// the same size, the standard accommodation real Hearts uses for non-four-handed
+    /// tables. Each dealt card is paired with its index in this deal's shuffled (and
+    /// discard-adjusted) order, so a replay export can track a specific physical card
+    /// across the whole game independent of where it ends up being played from.
+    pub fn deal(&mut self, num_players: usize) -> Vec<Vec<(Card, usize)>> {
+        let hand_size = self.cards.len() / num_players;
+        let to_discard = self.cards.len() - hand_size * num_players;
+
+        if to_discard > 0 {
+            // Tie-break on suit as well as rank so the choice of which low cards to set
+            // aside doesn't depend on the deck's array order — that order rotates between
+            // replays of the same deal (see `Deck::rotate`), and a tie-break that only
+            // looked at array position would silently discard different physical cards
+            // across rotations, breaking duplicate-deal reproducibility.
+            let mut by_rank = self.cards.clone();
+            by_rank.sort_by_key(|c| (c.rank, c.suit));
+            for card in by_rank.into_iter().take(to_discard) {
+                if let Some(pos) = self.cards.iter().position(|c| *c == card) {
+                    self.cards.remove(pos);
+                }
+            }
+        }
+
+        let mut hands: Vec<Vec<(Card, usize)>> = vec![Vec::new(); num_players];
+
+        for (deck_index, card) in self.cards.drain(..).enumerate() {
+            hands[deck_index % num_players].push((card, deck_index));
+        }
+
+        hands
+            .iter_mut()
+            .for_each(|hand| hand.sort_by_key(|(card, _)| *card));
+        hands
+    }
+
+    /// Returns a new deck with the same cards rotated by `rotation` positions, used to
+    /// re-deal the same deal to a different seat rotation (e.g. in duplicate-style
+    /// tournament play).
+    pub fn rotate(&self, rotation: usize) -> Deck {
+        let mut new_cards = self.cards.clone();
+        new_cards.rotate_left(rotation);
+        Deck { cards: new_cards }
+    }
+}
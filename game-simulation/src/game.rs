@@ -1,7 +1,8 @@
 use crate::card::Card;
 use crate::deck::Deck;
 use crate::player::Player;
-use crate::strategy::Strategy;
+use crate::replay::{GameLog, GameReplay, MoveRecord, ReplayCard, ReplayTrick};
+use crate::strategy::{PlayingStrategy, Strategy};
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize)]
@@ -15,6 +16,9 @@ pub struct GameResult {
     pub tricks: Vec<Trick>,
     pub final_scores: Vec<(String, u8)>,
     pub winner: String,
+    /// The name of the player who shot the moon (captured all 26 penalty points) this
+    /// hand, if anyone did. `final_scores` already reflects the inverted scoring.
+    pub moon_shooter: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -24,6 +28,56 @@ pub struct GameStats {
     pub scores: Vec<(String, u8)>,
     pub tricks: Vec<Trick>,
     pub total_points: u8,
+    pub shoot_the_moon: bool,
+}
+
+impl GameStats {
+    /// Builds the summary stats for one game, tagged with `game_id` so results from a
+    /// parallel batch or a tournament run can be merged back into a stable order.
+    pub fn from_result(game_id: usize, result: &GameResult) -> Self {
+        let total_points: u8 = result.final_scores.iter().map(|(_, score)| score).sum();
+
+        Self {
+            game_id,
+            winner: result.winner.clone(),
+            scores: result.final_scores.clone(),
+            tricks: result.tricks.clone(),
+            total_points,
+            shoot_the_moon: result.moon_shooter.is_some(),
+        }
+    }
+}
+
+/// The standard four-hand Hearts pre-trick passing rotation: pass left, then right,
+/// then across, then hold (no pass) on the fourth hand, then repeat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassDirection {
+    Left,
+    Right,
+    Across,
+    Hold,
+}
+
+impl PassDirection {
+    /// The passing direction for the `game_id`th game of a sequence, cycling
+    /// left -> right -> across -> hold.
+    pub fn for_game(game_id: usize) -> Self {
+        match game_id % 4 {
+            0 => PassDirection::Left,
+            1 => PassDirection::Right,
+            2 => PassDirection::Across,
+            _ => PassDirection::Hold,
+        }
+    }
+
+    fn recipient(self, seat: usize, num_players: usize) -> Option<usize> {
+        match self {
+            PassDirection::Left => Some((seat + 1) % num_players),
+            PassDirection::Right => Some((seat + num_players - 1) % num_players),
+            PassDirection::Across => Some((seat + num_players / 2) % num_players),
+            PassDirection::Hold => None,
+        }
+    }
 }
 
 pub struct HeartsGame {
@@ -31,24 +85,67 @@ pub struct HeartsGame {
     hearts_broken: bool,
     current_leader: usize,
     tricks: Vec<Trick>,
+    trick_count: usize,
 }
 
 impl HeartsGame {
-    pub fn new_with_strategies(player_configs: &[(&str, Strategy)]) -> Self {
-        let mut deck = Deck::new();
-        let hands = deck.deal(4);
-        let players: Vec<Player> = player_configs
+    /// Builds a game for 3-6 players, dealt from a fresh random deck. The number of
+    /// seats is taken from `player_configs.len()`.
+    pub fn new_with_strategies(
+        player_configs: &[(&str, Strategy)],
+        pass_direction: PassDirection,
+    ) -> Self {
+        Self::new_with_deck(player_configs, Deck::new(None), pass_direction)
+    }
+
+    /// Like `new_with_strategies`, but deals from a caller-supplied deck instead of a
+    /// fresh random one, so a seeded `Deck` (and therefore a reproducible deal) can be
+    /// threaded in by a tournament runner.
+    pub fn new_with_deck(
+        player_configs: &[(&str, Strategy)],
+        mut deck: Deck,
+        pass_direction: PassDirection,
+    ) -> Self {
+        let hands = deck.deal(player_configs.len());
+        let trick_count = hands.first().map(|hand| hand.len()).unwrap_or(0);
+        let mut players: Vec<Player> = player_configs
             .iter()
-            .zip(hands.into_iter())
-            .map(|((name, strategy), hand)| Player::with_strategy(name, hand, strategy.clone()))
+            .zip(hands)
+            .map(|((name, strategy), hand)| Player::dealt(name, hand, strategy.clone()))
             .collect();
 
+        Self::pass_cards(&mut players, pass_direction);
+
         let current_leader = Self::find_starting_player(&players);
         Self {
             players,
             hearts_broken: false,
             current_leader,
             tricks: Vec::new(),
+            trick_count,
+        }
+    }
+
+    /// Runs the pre-trick passing phase in place: each seat picks 3 cards via its own
+    /// `Strategy::choose_cards_to_pass`, which are removed from their hand and handed
+    /// to whichever seat `direction` points to (a no-op on a `Hold` hand).
+    fn pass_cards(players: &mut [Player], direction: PassDirection) {
+        let num_players = players.len();
+
+        let mut outgoing: Vec<Option<(usize, [Card; 3])>> = vec![None; num_players];
+        for seat in 0..num_players {
+            if let Some(recipient) = direction.recipient(seat, num_players) {
+                let cards = players[seat]
+                    .strategy
+                    .choose_cards_to_pass(&players[seat].hand, direction);
+                players[seat].hand.retain(|c| !cards.contains(c));
+                outgoing[seat] = Some((recipient, cards));
+            }
+        }
+
+        for (recipient, cards) in outgoing.into_iter().flatten() {
+            players[recipient].hand.extend(cards);
+            players[recipient].hand.sort();
         }
     }
 
@@ -95,14 +192,18 @@ impl HeartsGame {
             .collect()
     }
 
-    fn get_valid_moves(
-        &self,
+    /// The legality rules in their pure form (two-of-clubs lead, must-follow-suit,
+    /// no-hearts-until-broken, no-penalties-on-the-first-trick), independent of any
+    /// live `HeartsGame` instance so rollouts can reuse them from an arbitrary state.
+    fn valid_moves_for(
         hand: &[Card],
         lead_suit: Option<char>,
         is_first_card: bool,
+        hearts_broken: bool,
+        is_first_trick: bool,
     ) -> Vec<Card> {
         // First card of the first trick must be 2 of clubs
-        if is_first_card && self.tricks.is_empty() {
+        if is_first_card && is_first_trick {
             let two_clubs = Self::get_two_of_clubs(hand);
             if !two_clubs.is_empty() {
                 return two_clubs;
@@ -120,14 +221,14 @@ impl HeartsGame {
         // Leading a trick
         if is_first_card {
             // Can't lead hearts until broken
-            if !self.hearts_broken {
+            if !hearts_broken {
                 return Self::avoid_hearts(hand);
             }
             return hand.to_vec();
         }
 
         // Can't play penalties on first trick
-        if self.tricks.is_empty() {
+        if is_first_trick {
             return Self::avoid_penalties(hand);
         }
 
@@ -148,32 +249,95 @@ impl HeartsGame {
         trick_cards.iter().map(|(card, _)| card.score()).sum()
     }
 
-    pub fn play_trick(&mut self) -> Trick {
-        let mut trick_cards: Vec<(Card, usize)> = Vec::new();
-        let mut current_player = self.current_leader;
-        let mut lead_suit = None;
-
-        for _ in 0..4 {
-            let hand = &self.players[current_player].hand;
-            let valid_moves = self.get_valid_moves(hand, lead_suit, trick_cards.is_empty());
-
-            let played_card = self.players[current_player].play_card(valid_moves, &trick_cards);
+    /// Plays a trick to completion starting from `trick_cards` (empty for a fresh
+    /// trick, partially filled to resume mid-trick), driving each seat's own
+    /// `Strategy`. Shared by the live game loop and by `MonteCarloStrategy`'s
+    /// determinized rollouts, which call this directly on a throwaway set of
+    /// determinized players. When `log` is `Some`, every decision is recorded as a
+    /// `MoveRecord`; rollouts pass `None` so they don't pay for recording they'll
+    /// never read.
+    pub(crate) fn play_out_trick(
+        players: &mut [Player],
+        leader: usize,
+        hearts_broken: &mut bool,
+        is_first_trick: bool,
+        previous_tricks: &[Trick],
+        mut trick_cards: Vec<(Card, usize)>,
+        mut log: Option<&mut Vec<MoveRecord>>,
+    ) -> (Vec<(Card, usize)>, usize, u8) {
+        let num_players = players.len();
+        let mut lead_suit = trick_cards.first().map(|(card, _)| card.suit);
+        let mut current_player = (leader + trick_cards.len()) % num_players;
+
+        for _ in trick_cards.len()..num_players {
+            let hand_before = players[current_player].hand.clone();
+            let valid_moves = Self::valid_moves_for(
+                &hand_before,
+                lead_suit,
+                trick_cards.is_empty(),
+                *hearts_broken,
+                is_first_trick,
+            );
+            let trick_cards_before = trick_cards.clone();
+            let hearts_broken_before = *hearts_broken;
+
+            let played_card = players[current_player].play_card(
+                valid_moves.clone(),
+                &trick_cards,
+                previous_tricks,
+                *hearts_broken,
+                current_player,
+                num_players,
+            );
+
+            if let Some(log) = log.as_deref_mut() {
+                log.push(MoveRecord {
+                    player_index: current_player,
+                    hand_before,
+                    valid_moves,
+                    trick_cards_before,
+                    hearts_broken_before,
+                    card_played: played_card,
+                });
+            }
 
             if trick_cards.is_empty() {
                 lead_suit = Some(played_card.suit);
                 if played_card.suit == 'H' {
-                    self.hearts_broken = true;
+                    *hearts_broken = true;
                 }
             }
 
             trick_cards.push((played_card, current_player));
-            current_player = (current_player + 1) % 4;
+            current_player = (current_player + 1) % num_players;
         }
 
         let lead_suit = trick_cards[0].0.suit;
         let winner = Self::determine_trick_winner(&trick_cards, lead_suit);
         let score = Self::calculate_trick_score(&trick_cards);
 
+        (trick_cards, winner, score)
+    }
+
+    pub fn play_trick(&mut self) -> Trick {
+        self.play_trick_logging(None)
+    }
+
+    fn play_trick_logging(&mut self, log: Option<&mut Vec<MoveRecord>>) -> Trick {
+        let is_first_trick = self.tricks.is_empty();
+        let mut hearts_broken = self.hearts_broken;
+
+        let (trick_cards, winner, score) = Self::play_out_trick(
+            &mut self.players,
+            self.current_leader,
+            &mut hearts_broken,
+            is_first_trick,
+            &self.tricks,
+            Vec::new(),
+            log,
+        );
+
+        self.hearts_broken = hearts_broken;
         self.current_leader = winner;
         self.players[winner].score += score;
 
@@ -184,19 +348,41 @@ impl HeartsGame {
     }
 
     pub fn play_game(&mut self) -> GameResult {
-        // Play all 13 tricks
-        for _ in 0..13 {
+        for _ in 0..self.trick_count {
             let trick = self.play_trick();
             self.tricks.push(trick);
         }
 
-        // Calculate final scores
-        let final_scores: Vec<(String, u8)> = self
+        self.finish_game()
+    }
+
+    /// Like `play_game`, but also returns a full move-by-move `GameLog` that
+    /// `HeartsGame::replay` can later use to deterministically reconstruct this exact
+    /// game without re-running any strategy or RNG.
+    pub fn play_game_with_log(&mut self) -> (GameResult, GameLog) {
+        let mut moves = Vec::new();
+        for _ in 0..self.trick_count {
+            let trick = self.play_trick_logging(Some(&mut moves));
+            self.tricks.push(trick);
+        }
+
+        let log = GameLog {
+            player_names: self.players.iter().map(|p| p.name.clone()).collect(),
+            moves,
+        };
+
+        (self.finish_game(), log)
+    }
+
+    fn finish_game(&self) -> GameResult {
+        let raw_scores: Vec<(String, u8)> = self
             .players
             .iter()
             .map(|p| (p.name.clone(), p.score))
             .collect();
 
+        let (final_scores, moon_shooter) = Self::apply_shoot_the_moon(raw_scores);
+
         // Find winner (player with lowest score)
         let winner = final_scores
             .iter()
@@ -208,6 +394,118 @@ impl HeartsGame {
             tricks: self.tricks.clone(),
             final_scores,
             winner,
+            moon_shooter,
+        }
+    }
+
+    /// If one seat captured all 26 penalty points (every heart plus the Queen of
+    /// Spades), inverts that hand's scoring: the shooter scores 0 and everyone else
+    /// takes 26, per the standard "shooting the moon" rule. Returns the (possibly
+    /// adjusted) scores alongside the shooter's name, if any.
+    fn apply_shoot_the_moon(mut scores: Vec<(String, u8)>) -> (Vec<(String, u8)>, Option<String>) {
+        let Some(shooter) = scores.iter().position(|(_, score)| *score == 26) else {
+            return (scores, None);
+        };
+        let shooter_name = scores[shooter].0.clone();
+        for (i, (_, score)) in scores.iter_mut().enumerate() {
+            *score = if i == shooter { 0 } else { 26 };
+        }
+        (scores, Some(shooter_name))
+    }
+
+    /// Builds a move-by-move `GameReplay` for a finished game, tagged with `game_id` so
+    /// it can be correlated with its `GameStats` in the companion summary output. Every
+    /// played card is annotated with its original deck index, looked up from whichever
+    /// seat it was originally dealt to (cards moved by the pre-trick pass keep the deck
+    /// index they were dealt with, not the one of the seat that played them).
+    pub fn game_replay(&self, game_id: usize) -> GameReplay {
+        let result = self.finish_game();
+        let player_names: Vec<String> = self.players.iter().map(|p| p.name.clone()).collect();
+
+        let deck_indices: std::collections::HashMap<Card, usize> = self
+            .players
+            .iter()
+            .flat_map(|p| p.initial_hand.iter().copied())
+            .collect();
+
+        let tricks = self
+            .tricks
+            .iter()
+            .map(|trick| {
+                let plays = trick
+                    .cards
+                    .iter()
+                    .map(|(card, player_index)| {
+                        let deck_index = *deck_indices
+                            .get(card)
+                            .expect("every played card was dealt from this deck");
+                        (*player_index, ReplayCard { card: *card, deck_index })
+                    })
+                    .collect();
+
+                ReplayTrick {
+                    plays,
+                    lead_suit: trick.cards[0].0.suit,
+                    winner: trick.winner,
+                    points: Self::calculate_trick_score(&trick.cards),
+                }
+            })
+            .collect();
+
+        GameReplay {
+            game_id,
+            player_names,
+            tricks,
+            final_scores: result.final_scores,
+            winner: result.winner,
+            moon_shooter: result.moon_shooter,
+        }
+    }
+
+    /// Deterministically reconstructs a game's tricks and final scores from a
+    /// recorded `GameLog`, without re-running any strategy or RNG. Used for debugging
+    /// a specific deal and for regenerating training data offline.
+    pub fn replay(log: &GameLog) -> GameResult {
+        let num_players = log.player_names.len();
+        let mut scores = vec![0u8; num_players];
+        let mut tricks = Vec::new();
+        let mut trick_cards: Vec<(Card, usize)> = Vec::new();
+
+        for mv in &log.moves {
+            trick_cards.push((mv.card_played, mv.player_index));
+            if trick_cards.len() == num_players {
+                let lead_suit = trick_cards[0].0.suit;
+                let winner = Self::determine_trick_winner(&trick_cards, lead_suit);
+                let score = Self::calculate_trick_score(&trick_cards);
+                scores[winner] += score;
+                tricks.push(Trick {
+                    cards: trick_cards.clone(),
+                    winner,
+                });
+                trick_cards.clear();
+            }
+        }
+
+        let raw_scores: Vec<(String, u8)> = log
+            .player_names
+            .iter()
+            .cloned()
+            .zip(scores)
+            .collect();
+
+        let (final_scores, moon_shooter) = Self::apply_shoot_the_moon(raw_scores);
+
+        let winner = final_scores
+            .iter()
+            .min_by_key(|(_, score)| *score)
+            .map(|(name, _)| name.clone())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        GameResult {
+            tricks,
+            final_scores,
+            winner,
+            moon_shooter,
         }
     }
 }
@@ -1,28 +1,76 @@
 mod card;
 mod deck;
 mod game;
+mod parallel;
 mod player;
+mod replay;
 mod strategy;
+mod tournament;
 
-use game::{GameResult, GameStats, HeartsGame};
-use serde_json;
+use clap::Parser;
+use game::GameStats;
 use std::fs::File;
 use std::io::BufWriter;
 use std::time::Instant;
-use strategy::{AggressiveStrategy, AvoidPointsStrategy, RandomStrategy, Strategy};
+use strategy::{AggressiveStrategy, AvoidPointsStrategy, MonteCarloStrategy, RandomStrategy, Strategy};
+use tournament::Tournament;
 
-fn create_game_stats(game_id: usize, result: &GameResult) -> GameStats {
-    let total_points: u8 = result.final_scores.iter().map(|(_, score)| score).sum();
+/// Simulates batches of Hearts games between configurable strategies.
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Number of games to simulate
+    #[arg(short = 'n', long, default_value_t = 1)]
+    num_games: usize,
 
-    GameStats {
-        game_id,
-        winner: result.winner.clone(),
-        scores: result.final_scores.clone(),
-        tricks: result.tricks.clone(),
-        total_points,
+    /// Base RNG seed; each game's deck is derived from this seed plus its game index
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+
+    /// Number of worker threads to split the batch across
+    #[arg(short, long, default_value_t = 4)]
+    threads: usize,
+
+    /// Strategy for each seat, in seating order: random, avoid, aggressive, or ai
+    #[arg(
+        short,
+        long,
+        num_args = 1..,
+        default_values = ["random", "random", "avoid", "aggressive"]
+    )]
+    players: Vec<String>,
+
+    /// Output file for the per-game summary stats
+    #[arg(short, long, default_value = "game_results.json")]
+    output: String,
+
+    /// Output file for the move-by-move replay export (tricks, per-card deck indices)
+    #[arg(long, default_value = "game_replay.json")]
+    replay_output: String,
+
+    /// Duplicate-bridge-style tournament mode: num_games becomes the number of seeded
+    /// deals, and each deal is replayed once per seat rotation instead of dealing a
+    /// fresh hand every game, for lower-variance strategy comparisons
+    #[arg(long)]
+    duplicate: bool,
+}
+
+/// Parses a seat's `--players` value into its `Strategy`. Accepts the same short names
+/// used across the CLI and training tooling: `random`, `avoid`, `aggressive`, `ai`.
+fn parse_strategy(name: &str) -> Result<Strategy, String> {
+    match name {
+        "random" => Ok(Strategy::Random(RandomStrategy)),
+        "avoid" => Ok(Strategy::AvoidPoints(AvoidPointsStrategy)),
+        "aggressive" => Ok(Strategy::Aggressive(AggressiveStrategy)),
+        "ai" => Ok(Strategy::MonteCarlo(MonteCarloStrategy::default())),
+        other => Err(format!(
+            "unknown strategy '{other}' (expected one of: random, avoid, aggressive, ai)"
+        )),
     }
 }
 
+const SEAT_NAMES: [&str; 6] = ["Alice", "Bob", "Charlie", "David", "Eve", "Frank"];
+
 #[allow(dead_code)]
 fn display_card(card: &card::Card) -> String {
     let rank_symbol = match card.rank {
@@ -56,36 +104,9 @@ fn display_game_result(stats: &GameStats) {
     }
 }
 
-fn main() {
-    let num_games = 1;
-    let start = Instant::now();
-
-    // Pre-allocate vector to avoid reallocations
-    let mut stats = Vec::with_capacity(num_games);
-    let player_configs = vec![
-        ("Alice", Strategy::Random(RandomStrategy)),
-        ("Bob", Strategy::Random(RandomStrategy)),
-        ("Charlie", Strategy::AvoidPoints(AvoidPointsStrategy)),
-        ("David", Strategy::Aggressive(AggressiveStrategy)),
-    ];
-
-    // Run games
-    for game_id in 0..num_games {
-        let mut game = HeartsGame::new_with_strategies(&player_configs);
-        let result = game.play_game();
-        stats.push(create_game_stats(game_id, &result));
-    }
-
-    // Write results efficiently using a buffered writer
-    let file = File::create("game_results.json").expect("Failed to create file");
-    let writer = BufWriter::new(file);
-    serde_json::to_writer(writer, &stats).expect("Failed to write JSON");
-
-    let duration = start.elapsed();
-    println!("Time to play and save {} games: {:?}", num_games, duration);
-    println!("Average time per game: {:?}", duration / num_games as u32);
-
-    // Display summary statistics
+/// Prints the win-rate/avg-score table for a completed batch, one row per seat in
+/// `player_configs`.
+fn display_statistics(stats: &[GameStats], player_configs: &[(&str, Strategy)]) {
     let total_games = stats.len();
     let wins_per_player: std::collections::HashMap<_, _> = stats.iter().map(|s| &s.winner).fold(
         std::collections::HashMap::new(),
@@ -98,7 +119,7 @@ fn main() {
     // Calculate average scores per player
     let mut total_scores: std::collections::HashMap<String, (u32, u32)> =
         std::collections::HashMap::new();
-    for game in &stats {
+    for game in stats {
         for (name, score) in &game.scores {
             let entry = total_scores.entry(name.clone()).or_insert((0, 0));
             entry.0 += *score as u32;
@@ -112,15 +133,10 @@ fn main() {
         "Player (Strategy)", "Win Rate", "Avg Score", "Total Wins"
     );
     println!("--------------------------------------------------------------");
-    for (name, strategy) in [
-        ("Alice", "Random"),
-        ("Bob", "Random"),
-        ("Charlie", "Avoid Points"),
-        ("David", "Aggressive"),
-    ] {
-        let wins = wins_per_player.get(name).copied().unwrap_or(0);
+    for (name, strategy) in player_configs {
+        let wins = wins_per_player.get(*name).copied().unwrap_or(0);
         let win_rate = (wins as f64 / total_games as f64) * 100.0;
-        let (total_score, games_played) = total_scores.get(name).unwrap_or(&(0, 0));
+        let (total_score, games_played) = total_scores.get(*name).unwrap_or(&(0, 0));
         let avg_score = if *games_played > 0 {
             *total_score as f64 / *games_played as f64
         } else {
@@ -128,12 +144,73 @@ fn main() {
         };
         println!(
             "{:<25} | {:>8.1}% | {:>9.1} | {:>10}",
-            format!("{} ({})", name, strategy),
+            format!("{} ({})", name, strategy.label()),
             win_rate,
             avg_score,
             wins
         );
     }
+}
+
+/// Prints the per-strategy mean points, win rate, standard deviation, and shoot-the-
+/// moon count from a duplicate tournament's aggregate report.
+fn display_tournament_aggregate(
+    per_strategy: &std::collections::HashMap<String, tournament::StrategyAggregate>,
+) {
+    println!("\nTournament Aggregate (by strategy):");
+    println!(
+        "{:<14} | {:>9} | {:>9} | {:>7} | {:>10}",
+        "Strategy", "Win Rate", "Avg Score", "Std Dev", "Moon Shots"
+    );
+    println!("------------------------------------------------------------");
+    for (label, aggregate) in per_strategy {
+        println!(
+            "{:<14} | {:>8.1}% | {:>9.2} | {:>7.2} | {:>10}",
+            label,
+            aggregate.win_rate() * 100.0,
+            aggregate.mean_points(),
+            aggregate.std_dev_points(),
+            aggregate.shoot_the_moon_count
+        );
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let start = Instant::now();
+
+    let player_configs: Vec<(&str, Strategy)> = cli
+        .players
+        .iter()
+        .enumerate()
+        .map(|(seat, name)| {
+            let strategy = parse_strategy(name).unwrap_or_else(|err| panic!("{err}"));
+            (SEAT_NAMES[seat % SEAT_NAMES.len()], strategy)
+        })
+        .collect();
+
+    let (stats, replays) = if cli.duplicate {
+        let report = Tournament::new(&player_configs, cli.num_games, cli.seed).run();
+        display_tournament_aggregate(&report.per_strategy);
+        (report.games, report.replays)
+    } else {
+        parallel::run_batch(&player_configs, cli.num_games, cli.threads, cli.seed)
+    };
+
+    // Write results efficiently using a buffered writer
+    let file = File::create(&cli.output).expect("Failed to create file");
+    let writer = BufWriter::new(file);
+    serde_json::to_writer(writer, &stats).expect("Failed to write JSON");
+
+    let replay_file = File::create(&cli.replay_output).expect("Failed to create file");
+    let replay_writer = BufWriter::new(replay_file);
+    serde_json::to_writer(replay_writer, &replays).expect("Failed to write JSON");
+
+    let duration = start.elapsed();
+    println!("Time to play and save {} games: {:?}", stats.len(), duration);
+    println!("Average time per game: {:?}", duration / stats.len().max(1) as u32);
+
+    display_statistics(&stats, &player_configs);
 
     // for game in stats {
     //     display_game_result(&game);
@@ -0,0 +1,55 @@
+use crate::deck::Deck;
+use crate::game::{GameStats, HeartsGame, PassDirection};
+use crate::replay::GameReplay;
+use crate::strategy::Strategy;
+use crossbeam::thread;
+
+/// Plays `num_games` independent games split across `num_threads` scoped worker
+/// threads, each building its own `Deck`/`HeartsGame` and returning its slice of
+/// `GameStats`/`GameReplay` pairs locally before they're merged back into game-id order.
+///
+/// Worker `w` plays games `w, w + num_threads, w + 2 * num_threads, ...`, and each
+/// game's deck is seeded from `base_seed.wrapping_add(game_id)`, so the merged result
+/// is identical no matter how many threads it was split across.
+pub fn run_batch(
+    player_configs: &[(&str, Strategy)],
+    num_games: usize,
+    num_threads: usize,
+    base_seed: u64,
+) -> (Vec<GameStats>, Vec<GameReplay>) {
+    let num_threads = num_threads.max(1).min(num_games.max(1));
+
+    let mut all_results: Vec<(GameStats, GameReplay)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..num_threads)
+            .map(|worker| {
+                scope.spawn(move |_| {
+                    let mut worker_results = Vec::new();
+                    let mut game_id = worker;
+                    while game_id < num_games {
+                        let deck = Deck::new(Some(base_seed.wrapping_add(game_id as u64)));
+                        let mut game = HeartsGame::new_with_deck(
+                            player_configs,
+                            deck,
+                            PassDirection::for_game(game_id),
+                        );
+                        let result = game.play_game();
+                        let stats = GameStats::from_result(game_id, &result);
+                        let replay = game.game_replay(game_id);
+                        worker_results.push((stats, replay));
+                        game_id += num_threads;
+                    }
+                    worker_results
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("worker thread panicked"))
+            .collect()
+    })
+    .expect("scoped threads panicked");
+
+    all_results.sort_by_key(|(stats, _)| stats.game_id);
+    all_results.into_iter().unzip()
+}
@@ -1,31 +1,62 @@
 use crate::card::Card;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
+use crate::game::Trick;
+use crate::strategy::{PlayingStrategy, Strategy};
 
 pub struct Player {
     pub name: String,
     pub hand: Vec<Card>,
+    /// This seat's original dealt hand, each card paired with its index in that deal's
+    /// shuffled deck. Never mutated after dealing; used to annotate cards with their
+    /// deck index when building a `GameReplay`. Empty for throwaway players built for a
+    /// strategy's internal rollouts, which never get replayed.
+    pub initial_hand: Vec<(Card, usize)>,
     pub score: u8,
+    pub strategy: Strategy,
 }
 
 impl Player {
-    pub fn new(name: &str, hand: Vec<Card>) -> Self {
+    pub fn with_strategy(name: &str, hand: Vec<Card>, strategy: Strategy) -> Self {
         Self {
             name: name.to_string(),
             hand,
+            initial_hand: Vec::new(),
             score: 0,
+            strategy,
         }
     }
 
-    pub fn play_card(&mut self, valid_moves: Vec<Card>) -> Card {
-        let chosen_card = if valid_moves.is_empty() {
-            // If no valid moves (shouldn't happen), pick a random card from hand
-            self.hand.choose(&mut thread_rng()).copied().unwrap_or(self.hand[0])
-        } else {
-            // Pick a random card from valid moves
-            valid_moves.choose(&mut thread_rng()).copied().unwrap_or(valid_moves[0])
-        };
-        
+    /// Builds a seat for a live, dealt game: `hand` is this seat's cards straight from
+    /// `Deck::deal`, each paired with its deck index, which is retained in
+    /// `initial_hand` for later replay export.
+    pub fn dealt(name: &str, hand: Vec<(Card, usize)>, strategy: Strategy) -> Self {
+        let plain_hand = hand.iter().map(|(card, _)| *card).collect();
+        Self {
+            name: name.to_string(),
+            hand: plain_hand,
+            initial_hand: hand,
+            score: 0,
+            strategy,
+        }
+    }
+
+    pub fn play_card(
+        &mut self,
+        valid_moves: Vec<Card>,
+        trick_cards: &[(Card, usize)],
+        previous_tricks: &[Trick],
+        hearts_broken: bool,
+        player_index: usize,
+        num_players: usize,
+    ) -> Card {
+        let chosen_card = self.strategy.choose_card(
+            &self.hand,
+            &valid_moves,
+            trick_cards,
+            previous_tricks,
+            hearts_broken,
+            player_index,
+            num_players,
+        );
         self.hand.retain(|c| *c != chosen_card);
         chosen_card
     }
@@ -0,0 +1,55 @@
+use crate::card::Card;
+use serde::{Deserialize, Serialize};
+
+/// A single decision point: the acting player, the hand and valid moves they were
+/// offered, the running trick state they saw, and the card they actually chose. Each
+/// record is a self-contained state -> move training example.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveRecord {
+    pub player_index: usize,
+    pub hand_before: Vec<Card>,
+    pub valid_moves: Vec<Card>,
+    pub trick_cards_before: Vec<(Card, usize)>,
+    pub hearts_broken_before: bool,
+    pub card_played: Card,
+}
+
+/// A full move-by-move record of a played game, sufficient to deterministically
+/// reconstruct its tricks and final scores without re-running any strategy or RNG.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameLog {
+    pub player_names: Vec<String>,
+    pub moves: Vec<MoveRecord>,
+}
+
+/// A card annotated with its index in this game's dealt deck (its position in the
+/// shuffled, discard-adjusted order at deal time), so a downstream viewer can track a
+/// specific physical card across the whole game independent of where it's played from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ReplayCard {
+    pub card: Card,
+    pub deck_index: usize,
+}
+
+/// One trick's full play-by-play: each play in the order it happened (seat, card),
+/// the suit that was led, the winning seat, and the points the trick was worth.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayTrick {
+    pub plays: Vec<(usize, ReplayCard)>,
+    pub lead_suit: char,
+    pub winner: usize,
+    pub points: u8,
+}
+
+/// A stable, documented move-by-move export of a played game: every trick's plays
+/// (with original-deck card indices) plus the final standings, meant to feed a
+/// web-based replay/visualizer rather than to reconstruct game state like `GameLog`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameReplay {
+    pub game_id: usize,
+    pub player_names: Vec<String>,
+    pub tricks: Vec<ReplayTrick>,
+    pub final_scores: Vec<(String, u8)>,
+    pub winner: String,
+    pub moon_shooter: Option<String>,
+}
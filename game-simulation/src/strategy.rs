@@ -1,14 +1,55 @@
 use crate::card::Card;
+use crate::deck::ordered_deck;
+use crate::game::{HeartsGame, PassDirection, Trick};
+use crate::player::Player;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
 
 pub trait PlayingStrategy {
-    fn choose_card(&self, hand: &[Card], valid_moves: &[Card], trick_cards: &[(Card, usize)]) -> Card;
+    fn choose_card(
+        &self,
+        hand: &[Card],
+        valid_moves: &[Card],
+        trick_cards: &[(Card, usize)],
+        previous_tricks: &[Trick],
+        hearts_broken: bool,
+        player_index: usize,
+        num_players: usize,
+    ) -> Card;
+
+    /// Picks 3 cards to pass before the first trick. Defaults to dumping the Queen of
+    /// Spades and the highest hearts, a reasonable pass for any strategy that doesn't
+    /// need to special-case the direction.
+    fn choose_cards_to_pass(&self, hand: &[Card], _direction: PassDirection) -> [Card; 3] {
+        let mut ranked: Vec<Card> = hand.to_vec();
+        ranked.sort_by_key(|card| {
+            let weight = if card.suit == 'S' && card.rank == 12 {
+                100 + card.rank as i32
+            } else if card.suit == 'H' {
+                50 + card.rank as i32
+            } else {
+                card.rank as i32
+            };
+            std::cmp::Reverse(weight)
+        });
+        [ranked[0], ranked[1], ranked[2]]
+    }
 }
 
 #[derive(Clone)]
 pub struct RandomStrategy;
 
 impl PlayingStrategy for RandomStrategy {
-    fn choose_card(&self, _hand: &[Card], valid_moves: &[Card], _trick_cards: &[(Card, usize)]) -> Card {
+    fn choose_card(
+        &self,
+        _hand: &[Card],
+        valid_moves: &[Card],
+        _trick_cards: &[(Card, usize)],
+        _previous_tricks: &[Trick],
+        _hearts_broken: bool,
+        _player_index: usize,
+        _num_players: usize,
+    ) -> Card {
         use rand::seq::SliceRandom;
         let mut rng = rand::thread_rng();
         valid_moves.choose(&mut rng).copied().unwrap_or(valid_moves[0])
@@ -19,7 +60,16 @@ impl PlayingStrategy for RandomStrategy {
 pub struct AvoidPointsStrategy;
 
 impl PlayingStrategy for AvoidPointsStrategy {
-    fn choose_card(&self, _hand: &[Card], valid_moves: &[Card], trick_cards: &[(Card, usize)]) -> Card {
+    fn choose_card(
+        &self,
+        _hand: &[Card],
+        valid_moves: &[Card],
+        trick_cards: &[(Card, usize)],
+        _previous_tricks: &[Trick],
+        _hearts_broken: bool,
+        _player_index: usize,
+        _num_players: usize,
+    ) -> Card {
         // If we're not leading, try to play highest card that won't win
         if !trick_cards.is_empty() {
             let lead_suit = trick_cards[0].0.suit;
@@ -59,7 +109,16 @@ impl PlayingStrategy for AvoidPointsStrategy {
 pub struct AggressiveStrategy;
 
 impl PlayingStrategy for AggressiveStrategy {
-    fn choose_card(&self, _hand: &[Card], valid_moves: &[Card], trick_cards: &[(Card, usize)]) -> Card {
+    fn choose_card(
+        &self,
+        _hand: &[Card],
+        valid_moves: &[Card],
+        trick_cards: &[(Card, usize)],
+        _previous_tricks: &[Trick],
+        _hearts_broken: bool,
+        _player_index: usize,
+        _num_players: usize,
+    ) -> Card {
         if trick_cards.is_empty() {
             // If leading, play highest non-penalty card if possible
             if let Some(safe_card) = valid_moves
@@ -71,10 +130,10 @@ impl PlayingStrategy for AggressiveStrategy {
             }
         } else {
             // Try to win the trick if no points are involved
-            let has_points = trick_cards.iter().any(|(card, _)| 
+            let has_points = trick_cards.iter().any(|(card, _)|
                 card.suit == 'H' || (card.suit == 'S' && card.rank == 12)
             );
-            
+
             if !has_points {
                 if let Some(winning_card) = valid_moves
                     .iter()
@@ -91,19 +150,353 @@ impl PlayingStrategy for AggressiveStrategy {
     }
 }
 
+/// Inspects completed tricks to find, for each seat, the suits they're known to be
+/// void in (they failed to follow the trick's lead suit), so determinizations never
+/// deal a player a suit they've already demonstrably run out of.
+fn infer_void_suits(previous_tricks: &[Trick], num_players: usize) -> Vec<Vec<char>> {
+    let mut voids = vec![Vec::new(); num_players];
+    for trick in previous_tricks {
+        let Some((lead_card, _)) = trick.cards.first() else {
+            continue;
+        };
+        let lead_suit = lead_card.suit;
+        for (card, player) in &trick.cards {
+            if card.suit != lead_suit && !voids[*player].contains(&card.suit) {
+                voids[*player].push(card.suit);
+            }
+        }
+    }
+    voids
+}
+
+/// The full deck minus every card already known to be somewhere specific: in our own
+/// hand, already played in `previous_tricks`, or already played in the current trick.
+fn unseen_cards(hand: &[Card], trick_cards: &[(Card, usize)], previous_tricks: &[Trick]) -> Vec<Card> {
+    let mut seen: Vec<Card> = hand.to_vec();
+    seen.extend(trick_cards.iter().map(|(card, _)| *card));
+    for trick in previous_tricks {
+        seen.extend(trick.cards.iter().map(|(card, _)| *card));
+    }
+    ordered_deck().into_iter().filter(|c| !seen.contains(c)).collect()
+}
+
+/// How many cards each seat still holds, derived from how many they've played so far.
+/// The starting hand size mirrors `Deck::deal`'s `52 / num_players` (17 for a 3-player
+/// table, 10 for 5, 8 for 6), not a hardcoded 13, since only 4-player tables deal evenly.
+fn hand_sizes(num_players: usize, previous_tricks: &[Trick], trick_cards: &[(Card, usize)]) -> Vec<usize> {
+    let initial_hand_size = 52 / num_players;
+    let mut played = vec![0usize; num_players];
+    for trick in previous_tricks {
+        for (_, player) in &trick.cards {
+            played[*player] += 1;
+        }
+    }
+    for (_, player) in trick_cards {
+        played[*player] += 1;
+    }
+    played
+        .into_iter()
+        .map(|count| initial_hand_size - count)
+        .collect()
+}
+
+/// Randomly deals `unseen` among every seat but `me`, respecting each seat's inferred
+/// voids and the number of cards they're still known to be holding.
+fn determinize_hands(
+    me: usize,
+    my_hand: Vec<Card>,
+    mut unseen: Vec<Card>,
+    hand_sizes: &[usize],
+    voids: &[Vec<char>],
+) -> Vec<Vec<Card>> {
+    let num_players = hand_sizes.len();
+    let mut hands: Vec<Vec<Card>> = vec![Vec::new(); num_players];
+    hands[me] = my_hand;
+
+    let mut rng = thread_rng();
+    unseen.shuffle(&mut rng);
+
+    for card in unseen {
+        let eligible: Vec<usize> = (0..num_players)
+            .filter(|&p| p != me && hands[p].len() < hand_sizes[p] && !voids[p].contains(&card.suit))
+            .collect();
+
+        let chosen = eligible.choose(&mut rng).copied().or_else(|| {
+            (0..num_players).find(|&p| p != me && hands[p].len() < hand_sizes[p])
+        });
+
+        if let Some(p) = chosen {
+            hands[p].push(card);
+        }
+    }
+
+    hands
+}
+
+/// Plays the rest of the hand to completion on a throwaway set of determinized
+/// players, starting mid-trick from `trick_cards`, and returns the deciding player
+/// `me`'s total penalty points for the rollout (0 instead of 26 if they shot the
+/// moon, since that's a win rather than the worst outcome).
+fn simulate_rest_of_hand(
+    players: &mut [Player],
+    mut leader: usize,
+    hearts_broken: &mut bool,
+    mut is_first_trick: bool,
+    real_previous_tricks: &[Trick],
+    mut trick_cards: Vec<(Card, usize)>,
+    me: usize,
+) -> u32 {
+    let num_players = players.len();
+    let mut rollout_tricks: Vec<Trick> = real_previous_tricks.to_vec();
+    let mut scores = vec![0u32; num_players];
+
+    loop {
+        let (cards, winner, score) = HeartsGame::play_out_trick(
+            players,
+            leader,
+            hearts_broken,
+            is_first_trick,
+            &rollout_tricks,
+            trick_cards,
+            None,
+        );
+        scores[winner] += score as u32;
+        rollout_tricks.push(Trick { cards, winner });
+
+        leader = winner;
+        is_first_trick = false;
+        trick_cards = Vec::new();
+
+        if players.iter().all(|p| p.hand.is_empty()) {
+            break;
+        }
+    }
+
+    if scores[me] == 26 {
+        0
+    } else {
+        scores[me]
+    }
+}
+
+/// Determinized playout search: for each candidate move, runs `determinizations`
+/// random deals of the unseen cards (honoring inferred voids), plays the rest of the
+/// hand out with a cheap heuristic (`AvoidPointsStrategy`) for every seat, and
+/// returns the candidate with the lowest average penalty points for the deciding
+/// player.
+#[derive(Clone)]
+pub struct MonteCarloStrategy {
+    pub determinizations: usize,
+}
+
+impl MonteCarloStrategy {
+    pub fn new(determinizations: usize) -> Self {
+        Self { determinizations }
+    }
+}
+
+impl Default for MonteCarloStrategy {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl PlayingStrategy for MonteCarloStrategy {
+    fn choose_card(
+        &self,
+        hand: &[Card],
+        valid_moves: &[Card],
+        trick_cards: &[(Card, usize)],
+        previous_tricks: &[Trick],
+        hearts_broken: bool,
+        player_index: usize,
+        num_players: usize,
+    ) -> Card {
+        if valid_moves.len() == 1 {
+            return valid_moves[0];
+        }
+
+        let is_first_trick = previous_tricks.is_empty();
+        let voids = infer_void_suits(previous_tricks, num_players);
+        let sizes = hand_sizes(num_players, previous_tricks, trick_cards);
+
+        let leader = (player_index + num_players - trick_cards.len()) % num_players;
+
+        let mut best_card = valid_moves[0];
+        let mut best_points = f64::MAX;
+
+        for &candidate in valid_moves {
+            let mut remaining_hand = hand.to_vec();
+            remaining_hand.retain(|c| *c != candidate);
+
+            let mut total_points = 0u32;
+            for _ in 0..self.determinizations {
+                let unseen = unseen_cards(hand, trick_cards, previous_tricks);
+                let hands = determinize_hands(
+                    player_index,
+                    remaining_hand.clone(),
+                    unseen,
+                    &sizes,
+                    &voids,
+                );
+
+                let mut players: Vec<Player> = hands
+                    .into_iter()
+                    .enumerate()
+                    .map(|(seat, seat_hand)| {
+                        Player::with_strategy(
+                            &format!("seat{}", seat),
+                            seat_hand,
+                            Strategy::AvoidPoints(AvoidPointsStrategy),
+                        )
+                    })
+                    .collect();
+
+                let mut played_trick_cards = trick_cards.to_vec();
+                played_trick_cards.push((candidate, player_index));
+
+                let mut hb = hearts_broken;
+                if trick_cards.is_empty() && candidate.suit == 'H' {
+                    hb = true;
+                }
+
+                total_points += simulate_rest_of_hand(
+                    &mut players,
+                    leader,
+                    &mut hb,
+                    is_first_trick,
+                    previous_tricks,
+                    played_trick_cards,
+                    player_index,
+                );
+            }
+
+            let average = total_points as f64 / self.determinizations as f64;
+            if average < best_points {
+                best_points = average;
+                best_card = candidate;
+            }
+        }
+
+        best_card
+    }
+}
+
 #[derive(Clone)]
 pub enum Strategy {
     Random(RandomStrategy),
     AvoidPoints(AvoidPointsStrategy),
     Aggressive(AggressiveStrategy),
+    MonteCarlo(MonteCarloStrategy),
 }
 
 impl PlayingStrategy for Strategy {
-    fn choose_card(&self, hand: &[Card], valid_moves: &[Card], trick_cards: &[(Card, usize)]) -> Card {
+    fn choose_card(
+        &self,
+        hand: &[Card],
+        valid_moves: &[Card],
+        trick_cards: &[(Card, usize)],
+        previous_tricks: &[Trick],
+        hearts_broken: bool,
+        player_index: usize,
+        num_players: usize,
+    ) -> Card {
+        match self {
+            Strategy::Random(s) => s.choose_card(
+                hand, valid_moves, trick_cards, previous_tricks, hearts_broken, player_index, num_players,
+            ),
+            Strategy::AvoidPoints(s) => s.choose_card(
+                hand, valid_moves, trick_cards, previous_tricks, hearts_broken, player_index, num_players,
+            ),
+            Strategy::Aggressive(s) => s.choose_card(
+                hand, valid_moves, trick_cards, previous_tricks, hearts_broken, player_index, num_players,
+            ),
+            Strategy::MonteCarlo(s) => s.choose_card(
+                hand, valid_moves, trick_cards, previous_tricks, hearts_broken, player_index, num_players,
+            ),
+        }
+    }
+}
+
+impl Strategy {
+    /// A stable label for grouping tournament results by strategy, independent of
+    /// whatever seat name a player happens to be given.
+    pub fn label(&self) -> &'static str {
         match self {
-            Strategy::Random(s) => s.choose_card(hand, valid_moves, trick_cards),
-            Strategy::AvoidPoints(s) => s.choose_card(hand, valid_moves, trick_cards),
-            Strategy::Aggressive(s) => s.choose_card(hand, valid_moves, trick_cards),
+            Strategy::Random(_) => "random",
+            Strategy::AvoidPoints(_) => "avoid_points",
+            Strategy::Aggressive(_) => "aggressive",
+            Strategy::MonteCarlo(_) => "monte_carlo",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infer_void_suits_flags_players_who_dont_follow_lead() {
+        let tricks = vec![Trick {
+            cards: vec![
+                (Card::new('S', 5), 0),
+                (Card::new('H', 2), 1),
+                (Card::new('S', 9), 2),
+                (Card::new('D', 3), 3),
+            ],
+            winner: 2,
+        }];
+
+        let voids = infer_void_suits(&tricks, 4);
+
+        assert_eq!(voids[0], Vec::<char>::new());
+        assert_eq!(voids[1], vec!['H']);
+        assert_eq!(voids[2], Vec::<char>::new());
+        assert_eq!(voids[3], vec!['D']);
+    }
+
+    #[test]
+    fn hand_sizes_counts_cards_already_played() {
+        let tricks = vec![Trick {
+            cards: vec![(Card::new('S', 5), 0), (Card::new('H', 2), 1)],
+            winner: 0,
+        }];
+        let trick_cards = vec![(Card::new('D', 3), 2)];
+
+        // 3-player tables deal 52 / 3 = 17 cards per seat, not 13.
+        let sizes = hand_sizes(3, &tricks, &trick_cards);
+        assert_eq!(sizes, vec![16, 16, 16]);
+
+        // 4-player tables still deal the familiar 13 cards per seat.
+        let sizes = hand_sizes(4, &tricks, &trick_cards);
+        assert_eq!(sizes, vec![12, 12, 12, 13]);
+    }
+
+    #[test]
+    fn determinize_hands_respects_hand_sizes_for_any_player_count() {
+        // Regression test: MonteCarloStrategy used to hardcode a 4-player table, which
+        // panicked on 5-6 player games and misdealt 3-player ones. This only exercises
+        // determinize_hands directly, but it's the helper that bug would have broken.
+        for num_players in 3..=6 {
+            let me = num_players - 1;
+            let my_hand = vec![Card::new('S', 14)];
+            let mut hand_sizes = vec![2usize; num_players];
+            hand_sizes[me] = my_hand.len();
+            let voids = vec![Vec::new(); num_players];
+
+            let unseen: Vec<Card> = ordered_deck()
+                .into_iter()
+                .filter(|c| *c != my_hand[0])
+                .take(2 * (num_players - 1))
+                .collect();
+
+            let hands = determinize_hands(me, my_hand.clone(), unseen, &hand_sizes, &voids);
+
+            assert_eq!(hands.len(), num_players);
+            assert_eq!(hands[me], my_hand);
+            for (seat, hand) in hands.iter().enumerate() {
+                assert_eq!(hand.len(), hand_sizes[seat]);
+            }
         }
     }
 }
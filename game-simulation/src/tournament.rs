@@ -0,0 +1,132 @@
+use crate::deck::Deck;
+use crate::game::{GameResult, GameStats, HeartsGame, PassDirection};
+use crate::replay::GameReplay;
+use crate::strategy::Strategy;
+use std::collections::HashMap;
+
+/// Running per-strategy totals accumulated across a tournament, from which mean
+/// penalty points, win rate, and standard deviation can be derived.
+#[derive(Debug, Clone, Default)]
+pub struct StrategyAggregate {
+    pub games_played: u32,
+    pub games_won: u32,
+    pub total_points: u64,
+    pub sum_squared_points: u64,
+    pub shoot_the_moon_count: u32,
+}
+
+impl StrategyAggregate {
+    pub fn mean_points(&self) -> f64 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            self.total_points as f64 / self.games_played as f64
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            self.games_won as f64 / self.games_played as f64
+        }
+    }
+
+    pub fn std_dev_points(&self) -> f64 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            let mean = self.mean_points();
+            let mean_of_squares = self.sum_squared_points as f64 / self.games_played as f64;
+            (mean_of_squares - mean * mean).max(0.0).sqrt()
+        }
+    }
+}
+
+/// The full result of a tournament run: the per-strategy aggregate report, plus every
+/// individual game's `GameStats` and `GameReplay` so a caller can serialize the whole
+/// run.
+pub struct TournamentReport {
+    pub per_strategy: HashMap<String, StrategyAggregate>,
+    pub games: Vec<GameStats>,
+    pub replays: Vec<GameReplay>,
+}
+
+/// Runs `n_deals` seeded, duplicate-dealt "boards" across a fixed lineup of named
+/// strategies and aggregates the results. Each deal is played once per seat rotation
+/// (`player_configs.len()` replays per deal, `n_deals * player_configs.len()` games in
+/// total), so every strategy sees the exact same cards from every seat and only the
+/// seating differs. This is duplicate-bridge-style variance reduction: since the luck
+/// of the deal is held constant, a strategy's real edge over another shows up in far
+/// fewer deals than independent random games would need. The same `seed` always
+/// reproduces the same sequence of deals.
+pub struct Tournament<'a> {
+    player_configs: &'a [(&'a str, Strategy)],
+    n_deals: usize,
+    seed: u64,
+}
+
+impl<'a> Tournament<'a> {
+    pub fn new(player_configs: &'a [(&'a str, Strategy)], n_deals: usize, seed: u64) -> Self {
+        Self {
+            player_configs,
+            n_deals,
+            seed,
+        }
+    }
+
+    pub fn run(&self) -> TournamentReport {
+        let num_players = self.player_configs.len();
+        let mut games = Vec::with_capacity(self.n_deals * num_players);
+        let mut replays = Vec::with_capacity(self.n_deals * num_players);
+        let mut per_strategy: HashMap<String, StrategyAggregate> = HashMap::new();
+        let mut game_id = 0;
+
+        for deal_id in 0..self.n_deals {
+            let base_deck = Deck::new(Some(self.seed.wrapping_add(deal_id as u64)));
+
+            for rotation in 0..num_players {
+                let deck = base_deck.rotate(rotation);
+                let mut game = HeartsGame::new_with_deck(
+                    self.player_configs,
+                    deck,
+                    PassDirection::for_game(game_id),
+                );
+                let result = game.play_game();
+
+                self.record_result(self.player_configs, &result, &mut per_strategy);
+                games.push(GameStats::from_result(game_id, &result));
+                replays.push(game.game_replay(game_id));
+                game_id += 1;
+            }
+        }
+
+        TournamentReport { per_strategy, games, replays }
+    }
+
+    fn record_result(
+        &self,
+        seated_configs: &[(&str, Strategy)],
+        result: &GameResult,
+        per_strategy: &mut HashMap<String, StrategyAggregate>,
+    ) {
+        for (name, score) in &result.final_scores {
+            let strategy = seated_configs
+                .iter()
+                .find(|(seat_name, _)| seat_name == name)
+                .map(|(_, strategy)| strategy)
+                .expect("every final score belongs to a seated player");
+
+            let aggregate = per_strategy.entry(strategy.label().to_string()).or_default();
+            aggregate.games_played += 1;
+            aggregate.total_points += *score as u64;
+            aggregate.sum_squared_points += (*score as u64).pow(2);
+            if *name == result.winner {
+                aggregate.games_won += 1;
+            }
+            if result.moon_shooter.as_deref() == Some(name.as_str()) {
+                aggregate.shoot_the_moon_count += 1;
+            }
+        }
+    }
+}